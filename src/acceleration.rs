@@ -0,0 +1,286 @@
+//! This module contains the `Acceleration` enum and its associated unit conversions.
+//! The `Acceleration` enum is used to represent accelerations in meters per second squared, standard gravities, etc.
+//! The `AccelerationUnit` enum is used to specify the unit of the acceleration.
+//! The `Acceleration` enum implements the `Unit` and `UnitConversion` traits, which offer the ability to 
+//! convert between different units of acceleration via the `to_base` and `to` methods.
+
+use std::ops::{Mul, Div};
+use std::fmt::Display;
+use crate::unit_traits::UnitConversion;
+use crate::unit_traits::Unit;
+use crate::speed::{Speed, SpeedUnit};
+use crate::time::Time;
+use crate::quantity::Quantity;
+use crate::dimension;
+use crate::utils;
+
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[allow(non_camel_case_types)]
+pub enum AccelerationUnit {
+    MetersPerSecondSquared,
+    mps2,
+    FeetPerSecondSquared,
+    fps2,
+    StandardGravity,
+    g0,
+    Gals,
+    Gal,
+}
+
+
+/// The `Acceleration` enum represents accelerations in meters per second squared, standard gravities, etc.
+#[derive(Debug, Clone, Copy)]
+pub enum Acceleration {
+    MetersPerSecondSquared(f64),
+    FeetPerSecondSquared(f64),
+    StandardGravity(f64),
+    Gals(f64),
+}
+
+impl Acceleration {
+    pub const METERS_PER_SECOND_SQUARED_PER_FPS2: f64 = 0.3048;
+    pub const METERS_PER_SECOND_SQUARED_PER_STANDARD_GRAVITY: f64 = 9.806_65;
+    pub const METERS_PER_SECOND_SQUARED_PER_GAL: f64 = 0.01;
+
+    /* ALIASES */
+
+    pub fn mps2(x: f64) -> Acceleration {
+        Acceleration::MetersPerSecondSquared(x)
+    }
+
+    pub fn fps2(x: f64) -> Acceleration {
+        Acceleration::FeetPerSecondSquared(x)
+    }
+
+    pub fn g0(x: f64) -> Acceleration {
+        Acceleration::StandardGravity(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn Gal(x: f64) -> Acceleration {
+        Acceleration::Gals(x)
+    }
+}
+
+impl Unit for Acceleration {
+    /// Returns the inner f64 value of the Acceleration enum.
+    fn value(&self) -> f64 {
+        match self {
+            Acceleration::MetersPerSecondSquared(x) => *x,
+            Acceleration::FeetPerSecondSquared(x) => *x,
+            Acceleration::StandardGravity(x) => *x,
+            Acceleration::Gals(x) => *x,
+        }
+    }
+}
+
+impl UnitConversion for Acceleration {
+    type Units = AccelerationUnit;
+
+    /// Returns the unit the Acceleration is expressed in.
+    fn unit(&self) -> AccelerationUnit {
+        match self {
+            Acceleration::MetersPerSecondSquared(_) => AccelerationUnit::MetersPerSecondSquared,
+            Acceleration::FeetPerSecondSquared(_) => AccelerationUnit::FeetPerSecondSquared,
+            Acceleration::StandardGravity(_) => AccelerationUnit::StandardGravity,
+            Acceleration::Gals(_) => AccelerationUnit::Gals,
+        }
+    }
+
+    /// Converts a given instance of a Acceleration enum into the base variant, `Acceleration::MetersPerSecondSquared`.
+    fn to_base(&self) -> Acceleration {
+        match self {
+            Acceleration::MetersPerSecondSquared(x) => Acceleration::MetersPerSecondSquared(*x),
+            Acceleration::FeetPerSecondSquared(x) => Acceleration::MetersPerSecondSquared(x * Acceleration::METERS_PER_SECOND_SQUARED_PER_FPS2),
+            Acceleration::StandardGravity(x) => Acceleration::MetersPerSecondSquared(x * Acceleration::METERS_PER_SECOND_SQUARED_PER_STANDARD_GRAVITY),
+            Acceleration::Gals(x) => Acceleration::MetersPerSecondSquared(x * Acceleration::METERS_PER_SECOND_SQUARED_PER_GAL),
+        }
+    }
+
+    /// Converts a given instance of a Acceleration enum into the specified variant as indicated by
+    /// the `AccelerationUnit` provided.
+    fn to(&self, variant: AccelerationUnit) -> Acceleration {
+        let base = self.to_base().value();
+        match variant {
+            AccelerationUnit::MetersPerSecondSquared | AccelerationUnit::mps2 => Acceleration::MetersPerSecondSquared(base),
+            AccelerationUnit::FeetPerSecondSquared | AccelerationUnit::fps2 => Acceleration::FeetPerSecondSquared(base / Acceleration::METERS_PER_SECOND_SQUARED_PER_FPS2),
+            AccelerationUnit::StandardGravity | AccelerationUnit::g0 => Acceleration::StandardGravity(base / Acceleration::METERS_PER_SECOND_SQUARED_PER_STANDARD_GRAVITY),
+            AccelerationUnit::Gals | AccelerationUnit::Gal => Acceleration::Gals(base / Acceleration::METERS_PER_SECOND_SQUARED_PER_GAL),
+        }
+    }
+}
+
+
+impl Mul<f64> for Acceleration {
+    type Output = Acceleration;
+
+    fn mul(self, other: f64) -> Acceleration {
+        match self {
+            Acceleration::MetersPerSecondSquared(x) => Acceleration::MetersPerSecondSquared(x * other),
+            Acceleration::FeetPerSecondSquared(x) => Acceleration::FeetPerSecondSquared(x * other),
+            Acceleration::StandardGravity(x) => Acceleration::StandardGravity(x * other),
+            Acceleration::Gals(x) => Acceleration::Gals(x * other),
+        }
+    }
+}
+
+
+impl Mul<Acceleration> for f64 {
+    type Output = Acceleration;
+
+    fn mul(self, other: Acceleration) -> Acceleration {
+        match other {
+            Acceleration::MetersPerSecondSquared(x) => Acceleration::MetersPerSecondSquared(x * self),
+            Acceleration::FeetPerSecondSquared(x) => Acceleration::FeetPerSecondSquared(x * self),
+            Acceleration::StandardGravity(x) => Acceleration::StandardGravity(x * self),
+            Acceleration::Gals(x) => Acceleration::Gals(x * self),
+        }
+    }
+}
+
+
+impl Div<f64> for Acceleration {
+    type Output = Acceleration;
+
+    fn div(self, other: f64) -> Acceleration {
+        match self {
+            Acceleration::MetersPerSecondSquared(x) => Acceleration::MetersPerSecondSquared(x / other),
+            Acceleration::FeetPerSecondSquared(x) => Acceleration::FeetPerSecondSquared(x / other),
+            Acceleration::StandardGravity(x) => Acceleration::StandardGravity(x / other),
+            Acceleration::Gals(x) => Acceleration::Gals(x / other),
+        }
+    }
+}
+
+
+impl Display for Acceleration {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (x, symbol, name) = match self {
+            Acceleration::MetersPerSecondSquared(x) => (x, "m/s²", "meters per second squared"),
+            Acceleration::FeetPerSecondSquared(x) => (x, "ft/s²", "feet per second squared"),
+            Acceleration::StandardGravity(x) => (x, "g₀", "standard gravities"),
+            Acceleration::Gals(x) => (x, "Gal", "gals"),
+        };
+        utils::fmt_quantity(f, *x, symbol, name)
+    }
+}
+
+
+impl Div<Time> for Speed {
+    type Output = Acceleration;
+
+    /// Divides a speed by a time. Feet per second produce feet per second squared and every
+    /// other speed produces meters per second squared.
+    fn div(self, rhs: Time) -> Acceleration {
+        let acceleration: Quantity<dimension::Acceleration> = Quantity::from(self) / Quantity::from(rhs);
+        match self {
+            Speed::FeetPerSecond(_) => Acceleration::from(acceleration).to(AccelerationUnit::FeetPerSecondSquared),
+            _ => Acceleration::from(acceleration),
+        }
+    }
+}
+
+
+impl Mul<Time> for Acceleration {
+    type Output = Speed;
+
+    /// Multiplies an acceleration by a time. Feet per second squared produce feet per second and
+    /// every other acceleration produces meters per second.
+    fn mul(self, rhs: Time) -> Speed {
+        let speed: Quantity<dimension::Speed> = Quantity::from(self) * Quantity::from(rhs);
+        match self {
+            Acceleration::FeetPerSecondSquared(_) => Speed::from(speed).to(SpeedUnit::FeetPerSecond),
+            _ => Speed::from(speed),
+        }
+    }
+}
+
+
+/*--------------------( Unit Tests )--------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::speed::SpeedUnit;
+
+    #[test]
+    fn test_to_base() {
+        let accel = Acceleration::StandardGravity(2.0);
+        match accel.to_base() {
+            Acceleration::MetersPerSecondSquared(x) => assert_eq!(x, 19.6133),
+            _ => panic!("expected Acceleration::MetersPerSecondSquared variant"),
+        }
+        assert_eq!(Acceleration::Gals(100.0).to_base().value(), 1.0);
+    }
+
+    #[test]
+    fn test_from_base() {
+        let accel = Acceleration::MetersPerSecondSquared(9.806_65);
+        match accel.to(AccelerationUnit::g0) {
+            Acceleration::StandardGravity(x) => assert_eq!(x, 1.0),
+            _ => panic!("expected Acceleration::StandardGravity variant"),
+        }
+        let g = Acceleration::StandardGravity(1.0);
+        assert!((g.to(AccelerationUnit::fps2).value() - 32.174_048_556).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cmp() {
+        assert!(Acceleration::StandardGravity(1.0) > Acceleration::FeetPerSecondSquared(32.0));
+        assert!(Acceleration::Gals(1.0) < Acceleration::MetersPerSecondSquared(1.0));
+        assert_eq!(Acceleration::Gals(100.0), Acceleration::MetersPerSecondSquared(1.0));
+        let fast = Acceleration::MetersPerSecondSquared(1.0e9);
+        assert_eq!(fast.to(AccelerationUnit::StandardGravity), fast);
+    }
+
+    #[test]
+    fn test_add_sub() {
+        match Acceleration::StandardGravity(1.0) + Acceleration::MetersPerSecondSquared(9.806_65) {
+            Acceleration::StandardGravity(x) => assert_eq!(x, 2.0),
+            _ => panic!("expected Acceleration::StandardGravity variant"),
+        }
+        match Acceleration::MetersPerSecondSquared(1.0) - Acceleration::Gals(50.0) {
+            Acceleration::MetersPerSecondSquared(x) => assert_eq!(x, 0.5),
+            _ => panic!("expected Acceleration::MetersPerSecondSquared variant"),
+        }
+    }
+
+    #[test]
+    fn test_scalar_ops() {
+        let accel = Acceleration::StandardGravity(3.0);
+        assert_eq!(accel * 2.0, Acceleration::StandardGravity(6.0));
+        assert_eq!(2.0 * accel, Acceleration::StandardGravity(6.0));
+        assert_eq!(accel / 3.0, Acceleration::StandardGravity(1.0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Acceleration::MetersPerSecondSquared(9.8)), "9.8 m/s²");
+        assert_eq!(format!("{}", Acceleration::FeetPerSecondSquared(32.0)), "32 ft/s²");
+        assert_eq!(format!("{}", Acceleration::StandardGravity(1.5)), "1.5 g₀");
+        assert_eq!(format!("{}", Acceleration::Gals(980.0)), "980 Gal");
+    }
+
+    #[test]
+    fn test_speed_div_time() {
+        match Speed::KilometersPerHour(100.0) / Time::Seconds(5.0) {
+            Acceleration::MetersPerSecondSquared(x) => assert!((x - 5.555_555_555_6).abs() < 1e-9),
+            _ => panic!("expected Acceleration::MetersPerSecondSquared variant"),
+        }
+        match Speed::FeetPerSecond(64.0) / Time::Seconds(2.0) {
+            Acceleration::FeetPerSecondSquared(x) => assert_eq!(x, 32.0),
+            _ => panic!("expected Acceleration::FeetPerSecondSquared variant"),
+        }
+    }
+
+    #[test]
+    fn test_acceleration_times_time() {
+        let speed = Acceleration::StandardGravity(1.0) * Time::Seconds(10.0);
+        match speed {
+            Speed::MetersPerSecond(x) => assert!((x - 98.0665).abs() < 1e-12),
+            _ => panic!("expected Speed::MetersPerSecond variant"),
+        }
+        assert!((speed.to(SpeedUnit::kph).value() - 353.0394).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,356 @@
+//! This module contains the `Angle` enum and its associated unit conversions.
+//! The `Angle` enum is used to represent angles in radians, degrees, turns, etc.
+//! The `AngleUnit` enum is used to specify the unit of the angle.
+//! The `Angle` enum implements the `Unit` and `UnitConversion` traits, which offer the ability to 
+//! convert between different units of angle via the `to_base` and `to` methods.
+
+use std::ops::{Mul, Div};
+use std::fmt::Display;
+use crate::unit_traits::UnitConversion;
+use crate::unit_traits::Unit;
+use std::f64::consts::PI;
+use crate::utils;
+
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[allow(non_camel_case_types)]
+pub enum AngleUnit {
+    Radians,
+    rad,
+    Degrees,
+    deg,
+    Gradians,
+    grad,
+    Arcminutes,
+    arcmin,
+    Arcseconds,
+    arcsec,
+    Turns,
+    turn,
+}
+
+
+/// The `Angle` enum represents angles in radians, degrees, turns, etc.
+#[derive(Debug, Clone, Copy)]
+pub enum Angle {
+    Radians(f64),
+    Degrees(f64),
+    Gradians(f64),
+    Arcminutes(f64),
+    Arcseconds(f64),
+    Turns(f64),
+}
+
+impl Angle {
+    pub const RADIANS_PER_DEGREE: f64 = PI / 180.0;
+    pub const RADIANS_PER_GRADIAN: f64 = PI / 200.0;
+    pub const RADIANS_PER_ARCMINUTE: f64 = PI / (180.0 * 60.0);
+    pub const RADIANS_PER_ARCSECOND: f64 = PI / (180.0 * 3600.0);
+    pub const RADIANS_PER_TURN: f64 = 2.0 * PI;
+
+    /* ALIASES */
+
+    pub fn rad(x: f64) -> Angle {
+        Angle::Radians(x)
+    }
+
+    pub fn deg(x: f64) -> Angle {
+        Angle::Degrees(x)
+    }
+
+    pub fn grad(x: f64) -> Angle {
+        Angle::Gradians(x)
+    }
+
+    pub fn arcmin(x: f64) -> Angle {
+        Angle::Arcminutes(x)
+    }
+
+    pub fn arcsec(x: f64) -> Angle {
+        Angle::Arcseconds(x)
+    }
+
+    pub fn turn(x: f64) -> Angle {
+        Angle::Turns(x)
+    }
+
+    /// Returns the angle `atan2(y, x)` in radians, i.e. the angle of the point `(x, y)` measured
+    /// counterclockwise from the positive x-axis.
+    pub fn atan2(y: f64, x: f64) -> Angle {
+        Angle::Radians(y.atan2(x))
+    }
+
+    /// Returns the sine of the angle.
+    pub fn sin(&self) -> f64 {
+        self.to_base().value().sin()
+    }
+
+    /// Returns the cosine of the angle.
+    pub fn cos(&self) -> f64 {
+        self.to_base().value().cos()
+    }
+
+    /// Returns the tangent of the angle.
+    pub fn tan(&self) -> f64 {
+        self.to_base().value().tan()
+    }
+
+    /// Wraps the angle into a single turn starting at zero, i.e. `[0, 2π)`, preserving its unit.
+    pub fn normalize(&self) -> Angle {
+        let turn = self.full_turn();
+        self.map(|x| x.rem_euclid(turn))
+    }
+
+    /// Wraps the angle into a single turn centered on zero, i.e. `(-π, π]`, preserving its unit.
+    pub fn normalize_signed(&self) -> Angle {
+        let turn = self.full_turn();
+        self.map(|x| {
+            let wrapped = x.rem_euclid(turn);
+            if wrapped > turn / 2.0 { wrapped - turn } else { wrapped }
+        })
+    }
+
+    /// The size of a full turn expressed in the unit of this angle.
+    fn full_turn(&self) -> f64 {
+        match self {
+            Angle::Radians(_) => 2.0 * PI,
+            Angle::Degrees(_) => 360.0,
+            Angle::Gradians(_) => 400.0,
+            Angle::Arcminutes(_) => 360.0 * 60.0,
+            Angle::Arcseconds(_) => 360.0 * 3600.0,
+            Angle::Turns(_) => 1.0,
+        }
+    }
+
+    /// Applies `f` to the inner value, preserving the unit of this angle.
+    fn map(&self, f: impl Fn(f64) -> f64) -> Angle {
+        match self {
+            Angle::Radians(x) => Angle::Radians(f(*x)),
+            Angle::Degrees(x) => Angle::Degrees(f(*x)),
+            Angle::Gradians(x) => Angle::Gradians(f(*x)),
+            Angle::Arcminutes(x) => Angle::Arcminutes(f(*x)),
+            Angle::Arcseconds(x) => Angle::Arcseconds(f(*x)),
+            Angle::Turns(x) => Angle::Turns(f(*x)),
+        }
+    }
+}
+
+impl Unit for Angle {
+    /// Returns the inner f64 value of the Angle enum.
+    fn value(&self) -> f64 {
+        match self {
+            Angle::Radians(x) => *x,
+            Angle::Degrees(x) => *x,
+            Angle::Gradians(x) => *x,
+            Angle::Arcminutes(x) => *x,
+            Angle::Arcseconds(x) => *x,
+            Angle::Turns(x) => *x,
+        }
+    }
+}
+
+impl UnitConversion for Angle {
+    type Units = AngleUnit;
+
+    /// Returns the unit the Angle is expressed in.
+    fn unit(&self) -> AngleUnit {
+        match self {
+            Angle::Radians(_) => AngleUnit::Radians,
+            Angle::Degrees(_) => AngleUnit::Degrees,
+            Angle::Gradians(_) => AngleUnit::Gradians,
+            Angle::Arcminutes(_) => AngleUnit::Arcminutes,
+            Angle::Arcseconds(_) => AngleUnit::Arcseconds,
+            Angle::Turns(_) => AngleUnit::Turns,
+        }
+    }
+
+    /// Converts a given instance of a Angle enum into the base variant, `Angle::Radians`.
+    fn to_base(&self) -> Angle {
+        match self {
+            Angle::Radians(x) => Angle::Radians(*x),
+            Angle::Degrees(x) => Angle::Radians(x * Angle::RADIANS_PER_DEGREE),
+            Angle::Gradians(x) => Angle::Radians(x * Angle::RADIANS_PER_GRADIAN),
+            Angle::Arcminutes(x) => Angle::Radians(x * Angle::RADIANS_PER_ARCMINUTE),
+            Angle::Arcseconds(x) => Angle::Radians(x * Angle::RADIANS_PER_ARCSECOND),
+            Angle::Turns(x) => Angle::Radians(x * Angle::RADIANS_PER_TURN),
+        }
+    }
+
+    /// Converts a given instance of a Angle enum into the specified variant as indicated by
+    /// the `AngleUnit` provided.
+    fn to(&self, variant: AngleUnit) -> Angle {
+        let base = self.to_base().value();
+        match variant {
+            AngleUnit::Radians | AngleUnit::rad => Angle::Radians(base),
+            AngleUnit::Degrees | AngleUnit::deg => Angle::Degrees(base / Angle::RADIANS_PER_DEGREE),
+            AngleUnit::Gradians | AngleUnit::grad => Angle::Gradians(base / Angle::RADIANS_PER_GRADIAN),
+            AngleUnit::Arcminutes | AngleUnit::arcmin => Angle::Arcminutes(base / Angle::RADIANS_PER_ARCMINUTE),
+            AngleUnit::Arcseconds | AngleUnit::arcsec => Angle::Arcseconds(base / Angle::RADIANS_PER_ARCSECOND),
+            AngleUnit::Turns | AngleUnit::turn => Angle::Turns(base / Angle::RADIANS_PER_TURN),
+        }
+    }
+}
+
+
+impl Mul<f64> for Angle {
+    type Output = Angle;
+
+    fn mul(self, other: f64) -> Angle {
+        match self {
+            Angle::Radians(x) => Angle::Radians(x * other),
+            Angle::Degrees(x) => Angle::Degrees(x * other),
+            Angle::Gradians(x) => Angle::Gradians(x * other),
+            Angle::Arcminutes(x) => Angle::Arcminutes(x * other),
+            Angle::Arcseconds(x) => Angle::Arcseconds(x * other),
+            Angle::Turns(x) => Angle::Turns(x * other),
+        }
+    }
+}
+
+
+impl Mul<Angle> for f64 {
+    type Output = Angle;
+
+    fn mul(self, other: Angle) -> Angle {
+        match other {
+            Angle::Radians(x) => Angle::Radians(x * self),
+            Angle::Degrees(x) => Angle::Degrees(x * self),
+            Angle::Gradians(x) => Angle::Gradians(x * self),
+            Angle::Arcminutes(x) => Angle::Arcminutes(x * self),
+            Angle::Arcseconds(x) => Angle::Arcseconds(x * self),
+            Angle::Turns(x) => Angle::Turns(x * self),
+        }
+    }
+}
+
+
+impl Div<f64> for Angle {
+    type Output = Angle;
+
+    fn div(self, other: f64) -> Angle {
+        match self {
+            Angle::Radians(x) => Angle::Radians(x / other),
+            Angle::Degrees(x) => Angle::Degrees(x / other),
+            Angle::Gradians(x) => Angle::Gradians(x / other),
+            Angle::Arcminutes(x) => Angle::Arcminutes(x / other),
+            Angle::Arcseconds(x) => Angle::Arcseconds(x / other),
+            Angle::Turns(x) => Angle::Turns(x / other),
+        }
+    }
+}
+
+
+impl Display for Angle {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (x, symbol, name) = match self {
+            Angle::Radians(x) => (x, "rad", "radians"),
+            Angle::Degrees(x) => (x, "deg", "degrees"),
+            Angle::Gradians(x) => (x, "grad", "gradians"),
+            Angle::Arcminutes(x) => (x, "arcmin", "arcminutes"),
+            Angle::Arcseconds(x) => (x, "arcsec", "arcseconds"),
+            Angle::Turns(x) => (x, "turn", "turns"),
+        };
+        utils::fmt_quantity(f, *x, symbol, name)
+    }
+}
+
+
+/*--------------------( Unit Tests )--------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::assert_close;
+
+    #[test]
+    fn test_to_base() {
+        match Angle::Degrees(180.0).to_base() {
+            Angle::Radians(x) => assert_close(x, PI),
+            _ => panic!("expected Angle::Radians variant"),
+        }
+        assert_close(Angle::Turns(0.25).to_base().value(), PI / 2.0);
+    }
+
+    #[test]
+    fn test_from_base() {
+        match Angle::Radians(PI).to(AngleUnit::deg) {
+            Angle::Degrees(x) => assert_close(x, 180.0),
+            _ => panic!("expected Angle::Degrees variant"),
+        }
+        let right = Angle::Degrees(90.0);
+        assert_close(right.to(AngleUnit::grad).value(), 100.0);
+        assert_close(right.to(AngleUnit::arcmin).value(), 5400.0);
+        assert_close(Angle::Degrees(1.0).to(AngleUnit::arcsec).value(), 3600.0);
+    }
+
+    #[test]
+    fn test_cmp() {
+        assert!(Angle::Degrees(90.0) < Angle::Radians(2.0));
+        assert!(Angle::Turns(1.0) > Angle::Degrees(359.0));
+        assert!(Angle::Gradians(100.0) > Angle::Degrees(89.0));
+        assert_eq!(Angle::Turns(1000.0), Angle::Degrees(360_000.0));
+        assert_eq!(Angle::Degrees(3.0e5), Angle::Arcminutes(1.8e7));
+    }
+
+    #[test]
+    fn test_add_sub() {
+        match Angle::Degrees(45.0) + Angle::Turns(0.125) {
+            Angle::Degrees(x) => assert_close(x, 90.0),
+            _ => panic!("expected Angle::Degrees variant"),
+        }
+        match Angle::Turns(1.0) - Angle::Degrees(90.0) {
+            Angle::Turns(x) => assert_close(x, 0.75),
+            _ => panic!("expected Angle::Turns variant"),
+        }
+    }
+
+    #[test]
+    fn test_scalar_ops() {
+        let angle = Angle::Degrees(30.0);
+        assert_close((angle * 3.0).to(AngleUnit::Degrees).value(), 90.0);
+        assert_close((3.0 * angle).to(AngleUnit::Degrees).value(), 90.0);
+        assert_close((angle / 2.0).to(AngleUnit::Degrees).value(), 15.0);
+    }
+
+    #[test]
+    fn test_trigonometry() {
+        assert_close(Angle::Degrees(30.0).sin(), 0.5);
+        assert_close(Angle::Degrees(60.0).cos(), 0.5);
+        assert_close(Angle::Degrees(45.0).tan(), 1.0);
+        assert_close(Angle::Turns(0.5).cos(), -1.0);
+
+        let heading = Angle::atan2(1.0, -1.0);
+        assert_close(heading.to(AngleUnit::Degrees).value(), 135.0);
+    }
+
+    #[test]
+    fn test_normalize() {
+        match Angle::Degrees(370.0).normalize() {
+            Angle::Degrees(x) => assert_close(x, 10.0),
+            _ => panic!("expected Angle::Degrees variant"),
+        }
+        assert_close(Angle::Degrees(-90.0).normalize().value(), 270.0);
+        assert_close(Angle::Degrees(360.0).normalize().value(), 0.0);
+        assert_close(Angle::Radians(-PI / 2.0).normalize().value(), 1.5 * PI);
+        assert_close(Angle::Turns(2.25).normalize().value(), 0.25);
+    }
+
+    #[test]
+    fn test_normalize_signed() {
+        match Angle::Degrees(270.0).normalize_signed() {
+            Angle::Degrees(x) => assert_close(x, -90.0),
+            _ => panic!("expected Angle::Degrees variant"),
+        }
+        assert_close(Angle::Degrees(180.0).normalize_signed().value(), 180.0);
+        assert_close(Angle::Degrees(-180.0).normalize_signed().value(), 180.0);
+        assert_close(Angle::Radians(3.0 * PI).normalize_signed().value(), PI);
+        assert_close(Angle::Gradians(-250.0).normalize_signed().value(), 150.0);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Angle::Radians(1.5)), "1.5 rad");
+        assert_eq!(format!("{}", Angle::Degrees(90.0)), "90 deg");
+        assert_eq!(format!("{}", Angle::Turns(2.0)), "2 turn");
+    }
+}
@@ -0,0 +1,266 @@
+//! This module contains the `AngularAcceleration` enum and its associated unit conversions.
+//! The `AngularAcceleration` enum is used to represent angular accelerations in radians per second squared, degrees per second squared, etc.
+//! The `AngularAccelerationUnit` enum is used to specify the unit of the angular acceleration.
+//! The `AngularAcceleration` enum implements the `Unit` and `UnitConversion` traits, which offer the ability to 
+//! convert between different units of angular acceleration via the `to_base` and `to` methods.
+
+use std::ops::{Mul, Div};
+use std::fmt::Display;
+use crate::unit_traits::UnitConversion;
+use crate::unit_traits::Unit;
+use std::f64::consts::PI;
+use crate::angular_speed::{AngularSpeed, AngularSpeedUnit};
+use crate::time::Time;
+use crate::quantity::Quantity;
+use crate::dimension;
+use crate::utils;
+
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[allow(non_camel_case_types)]
+pub enum AngularAccelerationUnit {
+    RadiansPerSecondSquared,
+    rad_per_s2,
+    DegreesPerSecondSquared,
+    deg_per_s2,
+    TurnsPerSecondSquared,
+    turn_per_s2,
+}
+
+
+/// The `AngularAcceleration` enum represents angular accelerations in radians per second squared, degrees per second squared, etc.
+#[derive(Debug, Clone, Copy)]
+pub enum AngularAcceleration {
+    RadiansPerSecondSquared(f64),
+    DegreesPerSecondSquared(f64),
+    TurnsPerSecondSquared(f64),
+}
+
+impl AngularAcceleration {
+    pub const RADIANS_PER_SECOND_SQUARED_PER_DEGREE_PER_SECOND_SQUARED: f64 = PI / 180.0;
+    pub const RADIANS_PER_SECOND_SQUARED_PER_TURN_PER_SECOND_SQUARED: f64 = 2.0 * PI;
+
+    /* ALIASES */
+
+    pub fn rad_per_s2(x: f64) -> AngularAcceleration {
+        AngularAcceleration::RadiansPerSecondSquared(x)
+    }
+
+    pub fn deg_per_s2(x: f64) -> AngularAcceleration {
+        AngularAcceleration::DegreesPerSecondSquared(x)
+    }
+
+    pub fn turn_per_s2(x: f64) -> AngularAcceleration {
+        AngularAcceleration::TurnsPerSecondSquared(x)
+    }
+}
+
+impl Unit for AngularAcceleration {
+    /// Returns the inner f64 value of the AngularAcceleration enum.
+    fn value(&self) -> f64 {
+        match self {
+            AngularAcceleration::RadiansPerSecondSquared(x) => *x,
+            AngularAcceleration::DegreesPerSecondSquared(x) => *x,
+            AngularAcceleration::TurnsPerSecondSquared(x) => *x,
+        }
+    }
+}
+
+impl UnitConversion for AngularAcceleration {
+    type Units = AngularAccelerationUnit;
+
+    /// Returns the unit the AngularAcceleration is expressed in.
+    fn unit(&self) -> AngularAccelerationUnit {
+        match self {
+            AngularAcceleration::RadiansPerSecondSquared(_) => AngularAccelerationUnit::RadiansPerSecondSquared,
+            AngularAcceleration::DegreesPerSecondSquared(_) => AngularAccelerationUnit::DegreesPerSecondSquared,
+            AngularAcceleration::TurnsPerSecondSquared(_) => AngularAccelerationUnit::TurnsPerSecondSquared,
+        }
+    }
+
+    /// Converts a given instance of a AngularAcceleration enum into the base variant, `AngularAcceleration::RadiansPerSecondSquared`.
+    fn to_base(&self) -> AngularAcceleration {
+        match self {
+            AngularAcceleration::RadiansPerSecondSquared(x) => AngularAcceleration::RadiansPerSecondSquared(*x),
+            AngularAcceleration::DegreesPerSecondSquared(x) => AngularAcceleration::RadiansPerSecondSquared(x * AngularAcceleration::RADIANS_PER_SECOND_SQUARED_PER_DEGREE_PER_SECOND_SQUARED),
+            AngularAcceleration::TurnsPerSecondSquared(x) => AngularAcceleration::RadiansPerSecondSquared(x * AngularAcceleration::RADIANS_PER_SECOND_SQUARED_PER_TURN_PER_SECOND_SQUARED),
+        }
+    }
+
+    /// Converts a given instance of a AngularAcceleration enum into the specified variant as indicated by
+    /// the `AngularAccelerationUnit` provided.
+    fn to(&self, variant: AngularAccelerationUnit) -> AngularAcceleration {
+        let base = self.to_base().value();
+        match variant {
+            AngularAccelerationUnit::RadiansPerSecondSquared | AngularAccelerationUnit::rad_per_s2 => AngularAcceleration::RadiansPerSecondSquared(base),
+            AngularAccelerationUnit::DegreesPerSecondSquared | AngularAccelerationUnit::deg_per_s2 => AngularAcceleration::DegreesPerSecondSquared(base / AngularAcceleration::RADIANS_PER_SECOND_SQUARED_PER_DEGREE_PER_SECOND_SQUARED),
+            AngularAccelerationUnit::TurnsPerSecondSquared | AngularAccelerationUnit::turn_per_s2 => AngularAcceleration::TurnsPerSecondSquared(base / AngularAcceleration::RADIANS_PER_SECOND_SQUARED_PER_TURN_PER_SECOND_SQUARED),
+        }
+    }
+}
+
+
+impl Mul<f64> for AngularAcceleration {
+    type Output = AngularAcceleration;
+
+    fn mul(self, other: f64) -> AngularAcceleration {
+        match self {
+            AngularAcceleration::RadiansPerSecondSquared(x) => AngularAcceleration::RadiansPerSecondSquared(x * other),
+            AngularAcceleration::DegreesPerSecondSquared(x) => AngularAcceleration::DegreesPerSecondSquared(x * other),
+            AngularAcceleration::TurnsPerSecondSquared(x) => AngularAcceleration::TurnsPerSecondSquared(x * other),
+        }
+    }
+}
+
+
+impl Mul<AngularAcceleration> for f64 {
+    type Output = AngularAcceleration;
+
+    fn mul(self, other: AngularAcceleration) -> AngularAcceleration {
+        match other {
+            AngularAcceleration::RadiansPerSecondSquared(x) => AngularAcceleration::RadiansPerSecondSquared(x * self),
+            AngularAcceleration::DegreesPerSecondSquared(x) => AngularAcceleration::DegreesPerSecondSquared(x * self),
+            AngularAcceleration::TurnsPerSecondSquared(x) => AngularAcceleration::TurnsPerSecondSquared(x * self),
+        }
+    }
+}
+
+
+impl Div<f64> for AngularAcceleration {
+    type Output = AngularAcceleration;
+
+    fn div(self, other: f64) -> AngularAcceleration {
+        match self {
+            AngularAcceleration::RadiansPerSecondSquared(x) => AngularAcceleration::RadiansPerSecondSquared(x / other),
+            AngularAcceleration::DegreesPerSecondSquared(x) => AngularAcceleration::DegreesPerSecondSquared(x / other),
+            AngularAcceleration::TurnsPerSecondSquared(x) => AngularAcceleration::TurnsPerSecondSquared(x / other),
+        }
+    }
+}
+
+
+impl Display for AngularAcceleration {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (x, symbol, name) = match self {
+            AngularAcceleration::RadiansPerSecondSquared(x) => (x, "rad/s²", "radians per second squared"),
+            AngularAcceleration::DegreesPerSecondSquared(x) => (x, "deg/s²", "degrees per second squared"),
+            AngularAcceleration::TurnsPerSecondSquared(x) => (x, "turn/s²", "turns per second squared"),
+        };
+        utils::fmt_quantity(f, *x, symbol, name)
+    }
+}
+
+
+impl Div<Time> for AngularSpeed {
+    type Output = AngularAcceleration;
+
+    /// Divides an angular speed by a time. Degrees and turns per second produce degrees and
+    /// turns per second squared; everything else produces radians per second squared.
+    fn div(self, rhs: Time) -> AngularAcceleration {
+        let unit = match self {
+            AngularSpeed::DegreesPerSecond(_) => AngularAccelerationUnit::DegreesPerSecondSquared,
+            AngularSpeed::TurnsPerSecond(_) => AngularAccelerationUnit::TurnsPerSecondSquared,
+            _ => AngularAccelerationUnit::RadiansPerSecondSquared,
+        };
+        let acceleration: Quantity<dimension::AngularAcceleration> = Quantity::from(self) / Quantity::from(rhs);
+        AngularAcceleration::from(acceleration).to(unit)
+    }
+}
+
+
+impl Mul<Time> for AngularAcceleration {
+    type Output = AngularSpeed;
+
+    /// Multiplies an angular acceleration by a time, producing the change in angular speed in
+    /// the unit matching the acceleration's unit.
+    fn mul(self, rhs: Time) -> AngularSpeed {
+        let unit = match self {
+            AngularAcceleration::RadiansPerSecondSquared(_) => AngularSpeedUnit::RadiansPerSecond,
+            AngularAcceleration::DegreesPerSecondSquared(_) => AngularSpeedUnit::DegreesPerSecond,
+            AngularAcceleration::TurnsPerSecondSquared(_) => AngularSpeedUnit::TurnsPerSecond,
+        };
+        let speed: Quantity<dimension::AngularSpeed> = Quantity::from(self) * Quantity::from(rhs);
+        AngularSpeed::from(speed).to(unit)
+    }
+}
+
+
+/*--------------------( Unit Tests )--------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::assert_close;
+
+    #[test]
+    fn test_to_base() {
+        match AngularAcceleration::DegreesPerSecondSquared(180.0).to_base() {
+            AngularAcceleration::RadiansPerSecondSquared(x) => assert_close(x, PI),
+            _ => panic!("expected AngularAcceleration::RadiansPerSecondSquared variant"),
+        }
+    }
+
+    #[test]
+    fn test_from_base() {
+        let accel = AngularAcceleration::RadiansPerSecondSquared(2.0 * PI);
+        match accel.to(AngularAccelerationUnit::turn_per_s2) {
+            AngularAcceleration::TurnsPerSecondSquared(x) => assert_close(x, 1.0),
+            _ => panic!("expected AngularAcceleration::TurnsPerSecondSquared variant"),
+        }
+    }
+
+    #[test]
+    fn test_cmp() {
+        assert!(AngularAcceleration::TurnsPerSecondSquared(1.0) > AngularAcceleration::RadiansPerSecondSquared(6.0));
+        assert!(AngularAcceleration::DegreesPerSecondSquared(1.0) < AngularAcceleration::RadiansPerSecondSquared(1.0));
+        let fast = AngularAcceleration::RadiansPerSecondSquared(1.37e7);
+        assert_eq!(fast.to(AngularAccelerationUnit::TurnsPerSecondSquared), fast);
+        assert_eq!(AngularAcceleration::TurnsPerSecondSquared(100.0), AngularAcceleration::DegreesPerSecondSquared(36_000.0));
+    }
+
+    #[test]
+    fn test_add_sub() {
+        match AngularAcceleration::DegreesPerSecondSquared(90.0) + AngularAcceleration::TurnsPerSecondSquared(0.25) {
+            AngularAcceleration::DegreesPerSecondSquared(x) => assert_close(x, 180.0),
+            _ => panic!("expected AngularAcceleration::DegreesPerSecondSquared variant"),
+        }
+        match AngularAcceleration::TurnsPerSecondSquared(1.0) - AngularAcceleration::DegreesPerSecondSquared(180.0) {
+            AngularAcceleration::TurnsPerSecondSquared(x) => assert_close(x, 0.5),
+            _ => panic!("expected AngularAcceleration::TurnsPerSecondSquared variant"),
+        }
+    }
+
+    #[test]
+    fn test_scalar_ops() {
+        let accel = AngularAcceleration::RadiansPerSecondSquared(4.0);
+        assert_eq!(accel * 2.0, AngularAcceleration::RadiansPerSecondSquared(8.0));
+        assert_eq!(2.0 * accel, AngularAcceleration::RadiansPerSecondSquared(8.0));
+        assert_eq!(accel / 2.0, AngularAcceleration::RadiansPerSecondSquared(2.0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", AngularAcceleration::RadiansPerSecondSquared(0.5)), "0.5 rad/s²");
+        assert_eq!(format!("{}", AngularAcceleration::DegreesPerSecondSquared(10.0)), "10 deg/s²");
+    }
+
+    #[test]
+    fn test_angular_speed_div_time() {
+        match AngularSpeed::DegreesPerSecond(90.0) / Time::Seconds(2.0) {
+            AngularAcceleration::DegreesPerSecondSquared(x) => assert_close(x, 45.0),
+            _ => panic!("expected AngularAcceleration::DegreesPerSecondSquared variant"),
+        }
+        match AngularSpeed::TurnsPerMinute(60.0) / Time::Seconds(2.0) {
+            AngularAcceleration::RadiansPerSecondSquared(x) => assert_close(x, PI),
+            _ => panic!("expected AngularAcceleration::RadiansPerSecondSquared variant"),
+        }
+    }
+
+    #[test]
+    fn test_angular_acceleration_times_time() {
+        match AngularAcceleration::TurnsPerSecondSquared(0.5) * Time::Seconds(4.0) {
+            AngularSpeed::TurnsPerSecond(x) => assert_close(x, 2.0),
+            _ => panic!("expected AngularSpeed::TurnsPerSecond variant"),
+        }
+    }
+}
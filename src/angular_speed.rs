@@ -0,0 +1,291 @@
+//! This module contains the `AngularSpeed` enum and its associated unit conversions.
+//! The `AngularSpeed` enum is used to represent angular speeds in radians per second, degrees per second, turns per minute, etc.
+//! The `AngularSpeedUnit` enum is used to specify the unit of the angular speed.
+//! The `AngularSpeed` enum implements the `Unit` and `UnitConversion` traits, which offer the ability to 
+//! convert between different units of angular speed via the `to_base` and `to` methods.
+
+use std::ops::{Mul, Div};
+use std::fmt::Display;
+use crate::unit_traits::UnitConversion;
+use crate::unit_traits::Unit;
+use std::f64::consts::PI;
+use crate::angle::{Angle, AngleUnit};
+use crate::time::Time;
+use crate::quantity::Quantity;
+use crate::dimension;
+use crate::utils;
+
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[allow(non_camel_case_types)]
+pub enum AngularSpeedUnit {
+    RadiansPerSecond,
+    rad_per_s,
+    DegreesPerSecond,
+    deg_per_s,
+    TurnsPerSecond,
+    turn_per_s,
+    TurnsPerMinute,
+    turn_per_min,
+}
+
+
+/// The `AngularSpeed` enum represents angular speeds in radians per second, degrees per second, turns per minute, etc.
+#[derive(Debug, Clone, Copy)]
+pub enum AngularSpeed {
+    RadiansPerSecond(f64),
+    DegreesPerSecond(f64),
+    TurnsPerSecond(f64),
+    TurnsPerMinute(f64),
+}
+
+impl AngularSpeed {
+    pub const RADIANS_PER_SECOND_PER_DEGREE_PER_SECOND: f64 = PI / 180.0;
+    pub const RADIANS_PER_SECOND_PER_TURN_PER_SECOND: f64 = 2.0 * PI;
+    pub const RADIANS_PER_SECOND_PER_TURN_PER_MINUTE: f64 = 2.0 * PI / 60.0;
+
+    /* ALIASES */
+
+    pub fn rad_per_s(x: f64) -> AngularSpeed {
+        AngularSpeed::RadiansPerSecond(x)
+    }
+
+    pub fn deg_per_s(x: f64) -> AngularSpeed {
+        AngularSpeed::DegreesPerSecond(x)
+    }
+
+    pub fn turn_per_s(x: f64) -> AngularSpeed {
+        AngularSpeed::TurnsPerSecond(x)
+    }
+
+    pub fn turn_per_min(x: f64) -> AngularSpeed {
+        AngularSpeed::TurnsPerMinute(x)
+    }
+}
+
+impl Unit for AngularSpeed {
+    /// Returns the inner f64 value of the AngularSpeed enum.
+    fn value(&self) -> f64 {
+        match self {
+            AngularSpeed::RadiansPerSecond(x) => *x,
+            AngularSpeed::DegreesPerSecond(x) => *x,
+            AngularSpeed::TurnsPerSecond(x) => *x,
+            AngularSpeed::TurnsPerMinute(x) => *x,
+        }
+    }
+}
+
+impl UnitConversion for AngularSpeed {
+    type Units = AngularSpeedUnit;
+
+    /// Returns the unit the AngularSpeed is expressed in.
+    fn unit(&self) -> AngularSpeedUnit {
+        match self {
+            AngularSpeed::RadiansPerSecond(_) => AngularSpeedUnit::RadiansPerSecond,
+            AngularSpeed::DegreesPerSecond(_) => AngularSpeedUnit::DegreesPerSecond,
+            AngularSpeed::TurnsPerSecond(_) => AngularSpeedUnit::TurnsPerSecond,
+            AngularSpeed::TurnsPerMinute(_) => AngularSpeedUnit::TurnsPerMinute,
+        }
+    }
+
+    /// Converts a given instance of a AngularSpeed enum into the base variant, `AngularSpeed::RadiansPerSecond`.
+    fn to_base(&self) -> AngularSpeed {
+        match self {
+            AngularSpeed::RadiansPerSecond(x) => AngularSpeed::RadiansPerSecond(*x),
+            AngularSpeed::DegreesPerSecond(x) => AngularSpeed::RadiansPerSecond(x * AngularSpeed::RADIANS_PER_SECOND_PER_DEGREE_PER_SECOND),
+            AngularSpeed::TurnsPerSecond(x) => AngularSpeed::RadiansPerSecond(x * AngularSpeed::RADIANS_PER_SECOND_PER_TURN_PER_SECOND),
+            AngularSpeed::TurnsPerMinute(x) => AngularSpeed::RadiansPerSecond(x * AngularSpeed::RADIANS_PER_SECOND_PER_TURN_PER_MINUTE),
+        }
+    }
+
+    /// Converts a given instance of a AngularSpeed enum into the specified variant as indicated by
+    /// the `AngularSpeedUnit` provided.
+    fn to(&self, variant: AngularSpeedUnit) -> AngularSpeed {
+        let base = self.to_base().value();
+        match variant {
+            AngularSpeedUnit::RadiansPerSecond | AngularSpeedUnit::rad_per_s => AngularSpeed::RadiansPerSecond(base),
+            AngularSpeedUnit::DegreesPerSecond | AngularSpeedUnit::deg_per_s => AngularSpeed::DegreesPerSecond(base / AngularSpeed::RADIANS_PER_SECOND_PER_DEGREE_PER_SECOND),
+            AngularSpeedUnit::TurnsPerSecond | AngularSpeedUnit::turn_per_s => AngularSpeed::TurnsPerSecond(base / AngularSpeed::RADIANS_PER_SECOND_PER_TURN_PER_SECOND),
+            AngularSpeedUnit::TurnsPerMinute | AngularSpeedUnit::turn_per_min => AngularSpeed::TurnsPerMinute(base / AngularSpeed::RADIANS_PER_SECOND_PER_TURN_PER_MINUTE),
+        }
+    }
+}
+
+
+impl Mul<f64> for AngularSpeed {
+    type Output = AngularSpeed;
+
+    fn mul(self, other: f64) -> AngularSpeed {
+        match self {
+            AngularSpeed::RadiansPerSecond(x) => AngularSpeed::RadiansPerSecond(x * other),
+            AngularSpeed::DegreesPerSecond(x) => AngularSpeed::DegreesPerSecond(x * other),
+            AngularSpeed::TurnsPerSecond(x) => AngularSpeed::TurnsPerSecond(x * other),
+            AngularSpeed::TurnsPerMinute(x) => AngularSpeed::TurnsPerMinute(x * other),
+        }
+    }
+}
+
+
+impl Mul<AngularSpeed> for f64 {
+    type Output = AngularSpeed;
+
+    fn mul(self, other: AngularSpeed) -> AngularSpeed {
+        match other {
+            AngularSpeed::RadiansPerSecond(x) => AngularSpeed::RadiansPerSecond(x * self),
+            AngularSpeed::DegreesPerSecond(x) => AngularSpeed::DegreesPerSecond(x * self),
+            AngularSpeed::TurnsPerSecond(x) => AngularSpeed::TurnsPerSecond(x * self),
+            AngularSpeed::TurnsPerMinute(x) => AngularSpeed::TurnsPerMinute(x * self),
+        }
+    }
+}
+
+
+impl Div<f64> for AngularSpeed {
+    type Output = AngularSpeed;
+
+    fn div(self, other: f64) -> AngularSpeed {
+        match self {
+            AngularSpeed::RadiansPerSecond(x) => AngularSpeed::RadiansPerSecond(x / other),
+            AngularSpeed::DegreesPerSecond(x) => AngularSpeed::DegreesPerSecond(x / other),
+            AngularSpeed::TurnsPerSecond(x) => AngularSpeed::TurnsPerSecond(x / other),
+            AngularSpeed::TurnsPerMinute(x) => AngularSpeed::TurnsPerMinute(x / other),
+        }
+    }
+}
+
+
+impl Display for AngularSpeed {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (x, symbol, name) = match self {
+            AngularSpeed::RadiansPerSecond(x) => (x, "rad/s", "radians per second"),
+            AngularSpeed::DegreesPerSecond(x) => (x, "deg/s", "degrees per second"),
+            AngularSpeed::TurnsPerSecond(x) => (x, "turn/s", "turns per second"),
+            AngularSpeed::TurnsPerMinute(x) => (x, "turn/min", "turns per minute"),
+        };
+        utils::fmt_quantity(f, *x, symbol, name)
+    }
+}
+
+
+impl Div<Time> for Angle {
+    type Output = AngularSpeed;
+
+    /// Divides an angle by a time. Degrees produce degrees per second and turns produce turns
+    /// per minute when divided by minutes or turns per second otherwise; everything else
+    /// produces radians per second.
+    fn div(self, rhs: Time) -> AngularSpeed {
+        let unit = match (self, rhs) {
+            (Angle::Degrees(_), _) => AngularSpeedUnit::DegreesPerSecond,
+            (Angle::Turns(_), Time::Minutes(_)) => AngularSpeedUnit::TurnsPerMinute,
+            (Angle::Turns(_), _) => AngularSpeedUnit::TurnsPerSecond,
+            _ => AngularSpeedUnit::RadiansPerSecond,
+        };
+        let speed: Quantity<dimension::AngularSpeed> = Quantity::from(self) / Quantity::from(rhs);
+        AngularSpeed::from(speed).to(unit)
+    }
+}
+
+
+impl Mul<Time> for AngularSpeed {
+    type Output = Angle;
+
+    /// Multiplies an angular speed by a time, producing the angle swept in the angle unit
+    /// matching the speed's unit.
+    fn mul(self, rhs: Time) -> Angle {
+        let unit = match self {
+            AngularSpeed::RadiansPerSecond(_) => AngleUnit::Radians,
+            AngularSpeed::DegreesPerSecond(_) => AngleUnit::Degrees,
+            AngularSpeed::TurnsPerSecond(_) | AngularSpeed::TurnsPerMinute(_) => AngleUnit::Turns,
+        };
+        let angle: Quantity<dimension::Angle> = Quantity::from(self) * Quantity::from(rhs);
+        Angle::from(angle).to(unit)
+    }
+}
+
+
+/*--------------------( Unit Tests )--------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::assert_close;
+
+    #[test]
+    fn test_to_base() {
+        match AngularSpeed::TurnsPerSecond(1.0).to_base() {
+            AngularSpeed::RadiansPerSecond(x) => assert_close(x, 2.0 * PI),
+            _ => panic!("expected AngularSpeed::RadiansPerSecond variant"),
+        }
+    }
+
+    #[test]
+    fn test_from_base() {
+        let speed = AngularSpeed::TurnsPerMinute(60.0);
+        match speed.to(AngularSpeedUnit::deg_per_s) {
+            AngularSpeed::DegreesPerSecond(x) => assert_close(x, 360.0),
+            _ => panic!("expected AngularSpeed::DegreesPerSecond variant"),
+        }
+    }
+
+    #[test]
+    fn test_cmp() {
+        assert!(AngularSpeed::TurnsPerSecond(1.0) > AngularSpeed::DegreesPerSecond(359.0));
+        assert!(AngularSpeed::RadiansPerSecond(1.0) < AngularSpeed::TurnsPerMinute(10.0));
+        assert_eq!(AngularSpeed::TurnsPerMinute(1.0e4), AngularSpeed::TurnsPerSecond(1.0e4 / 60.0));
+        assert_eq!(AngularSpeed::TurnsPerSecond(1.0e3), AngularSpeed::DegreesPerSecond(3.6e5));
+    }
+
+    #[test]
+    fn test_add_sub() {
+        match AngularSpeed::DegreesPerSecond(180.0) + AngularSpeed::TurnsPerSecond(0.5) {
+            AngularSpeed::DegreesPerSecond(x) => assert_close(x, 360.0),
+            _ => panic!("expected AngularSpeed::DegreesPerSecond variant"),
+        }
+        match AngularSpeed::TurnsPerMinute(120.0) - AngularSpeed::TurnsPerSecond(1.0) {
+            AngularSpeed::TurnsPerMinute(x) => assert_close(x, 60.0),
+            _ => panic!("expected AngularSpeed::TurnsPerMinute variant"),
+        }
+    }
+
+    #[test]
+    fn test_scalar_ops() {
+        let speed = AngularSpeed::DegreesPerSecond(10.0);
+        assert_eq!(speed * 2.0, AngularSpeed::DegreesPerSecond(20.0));
+        assert_eq!(2.0 * speed, AngularSpeed::DegreesPerSecond(20.0));
+        assert_eq!(speed / 2.0, AngularSpeed::DegreesPerSecond(5.0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", AngularSpeed::RadiansPerSecond(1.5)), "1.5 rad/s");
+        assert_eq!(format!("{}", AngularSpeed::TurnsPerMinute(33.0)), "33 turn/min");
+    }
+
+    #[test]
+    fn test_angle_div_time() {
+        match Angle::Degrees(90.0) / Time::Seconds(3.0) {
+            AngularSpeed::DegreesPerSecond(x) => assert_close(x, 30.0),
+            _ => panic!("expected AngularSpeed::DegreesPerSecond variant"),
+        }
+        match Angle::Turns(33.0) / Time::Minutes(1.0) {
+            AngularSpeed::TurnsPerMinute(x) => assert_close(x, 33.0),
+            _ => panic!("expected AngularSpeed::TurnsPerMinute variant"),
+        }
+        match Angle::Radians(PI) / Time::Seconds(2.0) {
+            AngularSpeed::RadiansPerSecond(x) => assert_close(x, PI / 2.0),
+            _ => panic!("expected AngularSpeed::RadiansPerSecond variant"),
+        }
+    }
+
+    #[test]
+    fn test_angular_speed_times_time() {
+        match AngularSpeed::DegreesPerSecond(15.0) * Time::Minutes(1.0) {
+            Angle::Degrees(x) => assert_close(x, 900.0),
+            _ => panic!("expected Angle::Degrees variant"),
+        }
+        match AngularSpeed::TurnsPerMinute(45.0) * Time::Seconds(20.0) {
+            Angle::Turns(x) => assert_close(x, 15.0),
+            _ => panic!("expected Angle::Turns variant"),
+        }
+    }
+}
@@ -1,15 +1,444 @@
+//! This module contains the `Area` enum and its associated unit conversions.
+//! The `Area` enum is used to represent areas in square meters, square feet, acres, etc.
+//! The `AreaUnit` enum is used to specify the unit of the area.
+//! The `Area` enum implements the `Unit` and `UnitConversion` traits, which offer the ability to 
+//! convert between different units of area via the `to_base` and `to` methods.
 
+use std::ops::{Mul, Div};
+use std::fmt::Display;
+use crate::unit_traits::UnitConversion;
+use crate::unit_traits::Unit;
+use crate::length::{Length, LengthUnit};
+use crate::quantity::Quantity;
+use crate::dimension;
+use crate::utils;
 
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[allow(non_camel_case_types)]
 pub enum AreaUnit {
     SqMillimeters,
+    mm2,
+    SqCentimeters,
+    cm2,
     SqMeters,
+    m2,
     SqKilometers,
+    km2,
+    SqInches,
+    in2,
     SqFeet,
+    ft2,
+    SqYards,
+    yd2,
+    SqMiles,
+    mi2,
+    Hectares,
+    ha,
+    Acres,
+    ac,
 }
 
+
+/// The `Area` enum represents areas in square meters, square feet, acres, etc.
+#[derive(Debug, Clone, Copy)]
 pub enum Area {
     SqMillimeters(f64),
+    SqCentimeters(f64),
     SqMeters(f64),
     SqKilometers(f64),
+    SqInches(f64),
     SqFeet(f64),
-}
\ No newline at end of file
+    SqYards(f64),
+    SqMiles(f64),
+    Hectares(f64),
+    Acres(f64),
+}
+
+impl Area {
+    pub const SQ_METERS_PER_SQ_MILLIMETER: f64 = 1.0e-6;
+    pub const SQ_METERS_PER_SQ_CENTIMETER: f64 = 1.0e-4;
+    pub const SQ_METERS_PER_SQ_KILOMETER: f64 = 1000.0 * 1000.0;
+    pub const SQ_METERS_PER_SQ_INCH: f64 = 0.0254 * 0.0254;
+    pub const SQ_METERS_PER_SQ_FOOT: f64 = 0.3048 * 0.3048;
+    pub const SQ_METERS_PER_SQ_YARD: f64 = 0.9144 * 0.9144;
+    pub const SQ_METERS_PER_SQ_MILE: f64 = 1609.344 * 1609.344;
+    pub const SQ_METERS_PER_HECTARE: f64 = 10_000.0;
+    pub const SQ_METERS_PER_ACRE: f64 = 4_046.856_422_4;
+
+    /* ALIASES */
+
+    pub fn mm2(x: f64) -> Area {
+        Area::SqMillimeters(x)
+    }
+
+    pub fn cm2(x: f64) -> Area {
+        Area::SqCentimeters(x)
+    }
+
+    pub fn m2(x: f64) -> Area {
+        Area::SqMeters(x)
+    }
+
+    pub fn km2(x: f64) -> Area {
+        Area::SqKilometers(x)
+    }
+
+    pub fn in2(x: f64) -> Area {
+        Area::SqInches(x)
+    }
+
+    pub fn ft2(x: f64) -> Area {
+        Area::SqFeet(x)
+    }
+
+    pub fn yd2(x: f64) -> Area {
+        Area::SqYards(x)
+    }
+
+    pub fn mi2(x: f64) -> Area {
+        Area::SqMiles(x)
+    }
+
+    pub fn ha(x: f64) -> Area {
+        Area::Hectares(x)
+    }
+
+    pub fn ac(x: f64) -> Area {
+        Area::Acres(x)
+    }
+}
+
+impl Unit for Area {
+    /// Returns the inner f64 value of the Area enum.
+    fn value(&self) -> f64 {
+        match self {
+            Area::SqMillimeters(x) => *x,
+            Area::SqCentimeters(x) => *x,
+            Area::SqMeters(x) => *x,
+            Area::SqKilometers(x) => *x,
+            Area::SqInches(x) => *x,
+            Area::SqFeet(x) => *x,
+            Area::SqYards(x) => *x,
+            Area::SqMiles(x) => *x,
+            Area::Hectares(x) => *x,
+            Area::Acres(x) => *x,
+        }
+    }
+}
+
+impl UnitConversion for Area {
+    type Units = AreaUnit;
+
+    /// Returns the unit the Area is expressed in.
+    fn unit(&self) -> AreaUnit {
+        match self {
+            Area::SqMillimeters(_) => AreaUnit::SqMillimeters,
+            Area::SqCentimeters(_) => AreaUnit::SqCentimeters,
+            Area::SqMeters(_) => AreaUnit::SqMeters,
+            Area::SqKilometers(_) => AreaUnit::SqKilometers,
+            Area::SqInches(_) => AreaUnit::SqInches,
+            Area::SqFeet(_) => AreaUnit::SqFeet,
+            Area::SqYards(_) => AreaUnit::SqYards,
+            Area::SqMiles(_) => AreaUnit::SqMiles,
+            Area::Hectares(_) => AreaUnit::Hectares,
+            Area::Acres(_) => AreaUnit::Acres,
+        }
+    }
+
+    /// Converts a given instance of a Area enum into the base variant, `Area::SqMeters`.
+    fn to_base(&self) -> Area {
+        match self {
+            Area::SqMillimeters(x) => Area::SqMeters(x * Area::SQ_METERS_PER_SQ_MILLIMETER),
+            Area::SqCentimeters(x) => Area::SqMeters(x * Area::SQ_METERS_PER_SQ_CENTIMETER),
+            Area::SqMeters(x) => Area::SqMeters(*x),
+            Area::SqKilometers(x) => Area::SqMeters(x * Area::SQ_METERS_PER_SQ_KILOMETER),
+            Area::SqInches(x) => Area::SqMeters(x * Area::SQ_METERS_PER_SQ_INCH),
+            Area::SqFeet(x) => Area::SqMeters(x * Area::SQ_METERS_PER_SQ_FOOT),
+            Area::SqYards(x) => Area::SqMeters(x * Area::SQ_METERS_PER_SQ_YARD),
+            Area::SqMiles(x) => Area::SqMeters(x * Area::SQ_METERS_PER_SQ_MILE),
+            Area::Hectares(x) => Area::SqMeters(x * Area::SQ_METERS_PER_HECTARE),
+            Area::Acres(x) => Area::SqMeters(x * Area::SQ_METERS_PER_ACRE),
+        }
+    }
+
+    /// Converts a given instance of a Area enum into the specified variant as indicated by
+    /// the `AreaUnit` provided.
+    fn to(&self, variant: AreaUnit) -> Area {
+        let base = self.to_base().value();
+        match variant {
+            AreaUnit::SqMillimeters | AreaUnit::mm2 => Area::SqMillimeters(base / Area::SQ_METERS_PER_SQ_MILLIMETER),
+            AreaUnit::SqCentimeters | AreaUnit::cm2 => Area::SqCentimeters(base / Area::SQ_METERS_PER_SQ_CENTIMETER),
+            AreaUnit::SqMeters | AreaUnit::m2 => Area::SqMeters(base),
+            AreaUnit::SqKilometers | AreaUnit::km2 => Area::SqKilometers(base / Area::SQ_METERS_PER_SQ_KILOMETER),
+            AreaUnit::SqInches | AreaUnit::in2 => Area::SqInches(base / Area::SQ_METERS_PER_SQ_INCH),
+            AreaUnit::SqFeet | AreaUnit::ft2 => Area::SqFeet(base / Area::SQ_METERS_PER_SQ_FOOT),
+            AreaUnit::SqYards | AreaUnit::yd2 => Area::SqYards(base / Area::SQ_METERS_PER_SQ_YARD),
+            AreaUnit::SqMiles | AreaUnit::mi2 => Area::SqMiles(base / Area::SQ_METERS_PER_SQ_MILE),
+            AreaUnit::Hectares | AreaUnit::ha => Area::Hectares(base / Area::SQ_METERS_PER_HECTARE),
+            AreaUnit::Acres | AreaUnit::ac => Area::Acres(base / Area::SQ_METERS_PER_ACRE),
+        }
+    }
+}
+
+
+impl Mul<f64> for Area {
+    type Output = Area;
+
+    fn mul(self, other: f64) -> Area {
+        match self {
+            Area::SqMillimeters(x) => Area::SqMillimeters(x * other),
+            Area::SqCentimeters(x) => Area::SqCentimeters(x * other),
+            Area::SqMeters(x) => Area::SqMeters(x * other),
+            Area::SqKilometers(x) => Area::SqKilometers(x * other),
+            Area::SqInches(x) => Area::SqInches(x * other),
+            Area::SqFeet(x) => Area::SqFeet(x * other),
+            Area::SqYards(x) => Area::SqYards(x * other),
+            Area::SqMiles(x) => Area::SqMiles(x * other),
+            Area::Hectares(x) => Area::Hectares(x * other),
+            Area::Acres(x) => Area::Acres(x * other),
+        }
+    }
+}
+
+
+impl Mul<Area> for f64 {
+    type Output = Area;
+
+    fn mul(self, other: Area) -> Area {
+        match other {
+            Area::SqMillimeters(x) => Area::SqMillimeters(x * self),
+            Area::SqCentimeters(x) => Area::SqCentimeters(x * self),
+            Area::SqMeters(x) => Area::SqMeters(x * self),
+            Area::SqKilometers(x) => Area::SqKilometers(x * self),
+            Area::SqInches(x) => Area::SqInches(x * self),
+            Area::SqFeet(x) => Area::SqFeet(x * self),
+            Area::SqYards(x) => Area::SqYards(x * self),
+            Area::SqMiles(x) => Area::SqMiles(x * self),
+            Area::Hectares(x) => Area::Hectares(x * self),
+            Area::Acres(x) => Area::Acres(x * self),
+        }
+    }
+}
+
+
+impl Div<f64> for Area {
+    type Output = Area;
+
+    fn div(self, other: f64) -> Area {
+        match self {
+            Area::SqMillimeters(x) => Area::SqMillimeters(x / other),
+            Area::SqCentimeters(x) => Area::SqCentimeters(x / other),
+            Area::SqMeters(x) => Area::SqMeters(x / other),
+            Area::SqKilometers(x) => Area::SqKilometers(x / other),
+            Area::SqInches(x) => Area::SqInches(x / other),
+            Area::SqFeet(x) => Area::SqFeet(x / other),
+            Area::SqYards(x) => Area::SqYards(x / other),
+            Area::SqMiles(x) => Area::SqMiles(x / other),
+            Area::Hectares(x) => Area::Hectares(x / other),
+            Area::Acres(x) => Area::Acres(x / other),
+        }
+    }
+}
+
+
+impl Display for Area {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (x, symbol, name) = match self {
+            Area::SqMillimeters(x) => (x, "mm²", "square millimeters"),
+            Area::SqCentimeters(x) => (x, "cm²", "square centimeters"),
+            Area::SqMeters(x) => (x, "m²", "square meters"),
+            Area::SqKilometers(x) => (x, "km²", "square kilometers"),
+            Area::SqInches(x) => (x, "in²", "square inches"),
+            Area::SqFeet(x) => (x, "ft²", "square feet"),
+            Area::SqYards(x) => (x, "yd²", "square yards"),
+            Area::SqMiles(x) => (x, "mi²", "square miles"),
+            Area::Hectares(x) => (x, "ha", "hectares"),
+            Area::Acres(x) => (x, "ac", "acres"),
+        };
+        utils::fmt_quantity(f, *x, symbol, name)
+    }
+}
+
+
+impl Mul<Length> for Length {
+    type Output = Area;
+
+    /// Multiplies two lengths into an area expressed in the square of the left operand's unit.
+    /// Nautical miles have no square counterpart, so they produce square kilometers.
+    fn mul(self, other: Length) -> Area {
+        let unit = match self {
+            Length::Millimeters(_) => AreaUnit::SqMillimeters,
+            Length::Centimeters(_) => AreaUnit::SqCentimeters,
+            Length::Meters(_) => AreaUnit::SqMeters,
+            Length::Kilometers(_) | Length::NauticalMiles(_) => AreaUnit::SqKilometers,
+            Length::Feet(_) => AreaUnit::SqFeet,
+            Length::Inches(_) => AreaUnit::SqInches,
+            Length::Yards(_) => AreaUnit::SqYards,
+            Length::Miles(_) => AreaUnit::SqMiles,
+        };
+        let area: Quantity<dimension::Area> = Quantity::from(self) * Quantity::from(other);
+        Area::from(area).to(unit)
+    }
+}
+
+
+impl Div<Length> for Area {
+    type Output = Length;
+
+    /// Divides an area by a length, producing a length in the unit matching the area's unit.
+    /// Hectares produce meters and acres produce feet.
+    fn div(self, other: Length) -> Length {
+        let unit = match self {
+            Area::SqMillimeters(_) => LengthUnit::Millimeters,
+            Area::SqCentimeters(_) => LengthUnit::Centimeters,
+            Area::SqMeters(_) | Area::Hectares(_) => LengthUnit::Meters,
+            Area::SqKilometers(_) => LengthUnit::Kilometers,
+            Area::SqInches(_) => LengthUnit::Inches,
+            Area::SqFeet(_) | Area::Acres(_) => LengthUnit::Feet,
+            Area::SqYards(_) => LengthUnit::Yards,
+            Area::SqMiles(_) => LengthUnit::Miles,
+        };
+        let length: Quantity<dimension::Length> = Quantity::from(self) / Quantity::from(other);
+        Length::from(length).to(unit)
+    }
+}
+
+
+/*--------------------( Unit Tests )--------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_base() {
+        let area = Area::SqKilometers(2.0);
+        match area.to_base() {
+            Area::SqMeters(x) => assert_eq!(x, 2_000_000.0),
+            _ => panic!("expected Area::SqMeters variant"),
+        }
+        let area = Area::Hectares(1.5);
+        assert_eq!(area.to_base().value(), 15_000.0);
+    }
+
+    #[test]
+    fn test_from_base() {
+        let area = Area::SqMeters(20_000.0);
+        match area.to(AreaUnit::ha) {
+            Area::Hectares(x) => assert_eq!(x, 2.0),
+            _ => panic!("expected Area::Hectares variant"),
+        }
+        match area.to(AreaUnit::SqKilometers) {
+            Area::SqKilometers(x) => assert_eq!(x, 0.02),
+            _ => panic!("expected Area::SqKilometers variant"),
+        }
+    }
+
+    #[test]
+    fn test_imperial_conversions() {
+        // An acre is 43,560 square feet and a square mile is 640 acres
+        let acre = Area::Acres(1.0);
+        assert!((acre.to(AreaUnit::SqFeet).value() - 43_560.0).abs() < 1e-9);
+        let sq_mile = Area::SqMiles(1.0);
+        assert!((sq_mile.to(AreaUnit::Acres).value() - 640.0).abs() < 1e-9);
+        let sq_yard = Area::SqYards(1.0);
+        assert!((sq_yard.to(AreaUnit::SqFeet).value() - 9.0).abs() < 1e-12);
+        let sq_foot = Area::SqFeet(1.0);
+        assert!((sq_foot.to(AreaUnit::SqInches).value() - 144.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(Area::Hectares(1.0), Area::SqMeters(10_000.0));
+        assert_eq!(Area::SqKilometers(1.0), Area::Hectares(100.0));
+        assert_eq!(Area::SqCentimeters(10_000.0), Area::SqMeters(1.0));
+        assert_eq!(Area::SqMeters(1.0e9).to(AreaUnit::SqMiles), Area::SqMeters(1.0e9));
+    }
+
+    #[test]
+    fn test_cmp() {
+        assert!(Area::Acres(1.0) < Area::Hectares(1.0));
+        assert!(Area::SqMiles(1.0) > Area::SqKilometers(1.0));
+        assert!(Area::SqFeet(1.0) > Area::SqInches(143.0));
+    }
+
+    #[test]
+    fn test_add_different_units() {
+        let x = Area::Hectares(1.0);
+        let y = Area::SqMeters(5_000.0);
+        match x + y {
+            Area::Hectares(z) => assert_eq!(z, 1.5),
+            _ => panic!("expected Area::Hectares variant"),
+        }
+    }
+
+    #[test]
+    fn test_sub_different_units() {
+        let x = Area::SqKilometers(1.0);
+        let y = Area::Hectares(50.0);
+        match x - y {
+            Area::SqKilometers(z) => assert_eq!(z, 0.5),
+            _ => panic!("expected Area::SqKilometers variant"),
+        }
+    }
+
+    #[test]
+    fn test_scalar_ops() {
+        let area = Area::SqFeet(100.0);
+        assert_eq!(area * 2.0, Area::SqFeet(200.0));
+        assert_eq!(2.0 * area, Area::SqFeet(200.0));
+        assert_eq!(area / 4.0, Area::SqFeet(25.0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Area::SqMeters(12.5)), "12.5 m²");
+        assert_eq!(format!("{}", Area::SqFeet(1.0)), "1 ft²");
+        assert_eq!(format!("{}", Area::Hectares(3.0)), "3 ha");
+        assert_eq!(format!("{}", Area::Acres(2.0)), "2 ac");
+    }
+
+    #[test]
+    fn test_length_times_length() {
+        let floor = Length::Feet(20.0) * Length::Feet(12.5);
+        match floor {
+            Area::SqFeet(x) => assert_eq!(x, 250.0),
+            _ => panic!("expected Area::SqFeet variant"),
+        }
+
+        let plot = Length::Meters(100.0) * Length::Kilometers(0.25);
+        match plot {
+            Area::SqMeters(x) => assert_eq!(x, 25_000.0),
+            _ => panic!("expected Area::SqMeters variant"),
+        }
+        assert_eq!(plot, Area::Hectares(2.5));
+
+        let sea = Length::NauticalMiles(1.0) * Length::NauticalMiles(1.0);
+        match sea {
+            Area::SqKilometers(x) => assert!((x - 1.852 * 1.852).abs() < 1e-12),
+            _ => panic!("expected Area::SqKilometers variant"),
+        }
+    }
+
+    #[test]
+    fn test_area_div_length() {
+        let width = Area::SqFeet(250.0) / Length::Feet(20.0);
+        match width {
+            Length::Feet(x) => assert_eq!(x, 12.5),
+            _ => panic!("expected Length::Feet variant"),
+        }
+
+        let side = Area::Hectares(1.0) / Length::Meters(100.0);
+        match side {
+            Length::Meters(x) => assert_eq!(x, 100.0),
+            _ => panic!("expected Length::Meters variant"),
+        }
+
+        let side = Area::Acres(1.0) / Length::Feet(43_560.0);
+        match side {
+            Length::Feet(x) => assert!((x - 1.0).abs() < 1e-12),
+            _ => panic!("expected Length::Feet variant"),
+        }
+    }
+}
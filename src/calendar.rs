@@ -0,0 +1,411 @@
+//! This module contains the `CalendarSpan` and `CivilDate` types for calendar-aware arithmetic.
+//!
+//! A `Time` is a fixed number of seconds, so `Time::Years` cannot account for leap years and
+//! there is no fixed-length month at all. A `CalendarSpan` instead counts years, months and days
+//! and only takes on a length once it is applied to a `CivilDate`, a date in the proleptic
+//! Gregorian calendar. When adding months lands past the end of the target month, the day is
+//! clamped to its last day, so 31 January plus one month is 28 or 29 February.
+//!
+//! A span converts to a `Time` only through `CalendarSpan::to_time_approx`, which uses the mean
+//! Gregorian year and month and has to be asked for explicitly.
+
+use std::ops::{Add, Sub, Neg};
+use std::fmt::Display;
+use crate::unit_traits::UnitConversion;
+use crate::time::TimeUnit;
+use crate::Time;
+
+
+/// The number of days in each month of a common year, January first.
+const DAYS_PER_MONTH: [u32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+
+/// A span of calendar time in years, months and days. Each part is applied in that order and
+/// may be negative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CalendarSpan {
+    pub years: i32,
+    pub months: i32,
+    pub days: i64,
+}
+
+impl CalendarSpan {
+    pub const MONTHS_PER_YEAR: i32 = 12;
+
+    pub fn new(years: i32, months: i32, days: i64) -> CalendarSpan {
+        CalendarSpan { years, months, days }
+    }
+
+    pub fn years(years: i32) -> CalendarSpan {
+        CalendarSpan::new(years, 0, 0)
+    }
+
+    pub fn months(months: i32) -> CalendarSpan {
+        CalendarSpan::new(0, months, 0)
+    }
+
+    pub fn days(days: i64) -> CalendarSpan {
+        CalendarSpan::new(0, 0, days)
+    }
+
+    /// Approximates the span as a fixed `Time`, counting each year as a mean Gregorian year of
+    /// 365.2425 days and each month as a twelfth of one. The result is expressed in days.
+    ///
+    /// The actual length of a span depends on the date it is applied to, so prefer applying it
+    /// to a `CivilDate` and subtracting the dates when an exact figure is needed.
+    pub fn to_time_approx(&self) -> Time {
+        let months = self.years as f64 * CalendarSpan::MONTHS_PER_YEAR as f64 + self.months as f64;
+        let years = Time::GregorianYears(months / CalendarSpan::MONTHS_PER_YEAR as f64);
+        years.to(TimeUnit::Days) + Time::Days(self.days as f64)
+    }
+
+    /// Adds two spans part by part, or returns `None` if a part overflows.
+    pub fn checked_add(&self, other: CalendarSpan) -> Option<CalendarSpan> {
+        Some(CalendarSpan::new(
+            self.years.checked_add(other.years)?,
+            self.months.checked_add(other.months)?,
+            self.days.checked_add(other.days)?,
+        ))
+    }
+
+    /// Subtracts one span from another part by part, or returns `None` if a part overflows.
+    pub fn checked_sub(&self, other: CalendarSpan) -> Option<CalendarSpan> {
+        Some(CalendarSpan::new(
+            self.years.checked_sub(other.years)?,
+            self.months.checked_sub(other.months)?,
+            self.days.checked_sub(other.days)?,
+        ))
+    }
+
+    /// Negates the span, or returns `None` if a part is at its type's minimum, which has no
+    /// positive counterpart.
+    pub fn checked_neg(&self) -> Option<CalendarSpan> {
+        Some(CalendarSpan::new(self.years.checked_neg()?, self.months.checked_neg()?, self.days.checked_neg()?))
+    }
+}
+
+impl Add for CalendarSpan {
+    type Output = CalendarSpan;
+
+    /// Adds the spans part by part, panicking if a part overflows. Use
+    /// `CalendarSpan::checked_add` to handle that case instead.
+    fn add(self, other: CalendarSpan) -> CalendarSpan {
+        self.checked_add(other).expect("overflow when adding calendar spans")
+    }
+}
+
+impl Sub for CalendarSpan {
+    type Output = CalendarSpan;
+
+    /// Subtracts the spans part by part, panicking if a part overflows. Use
+    /// `CalendarSpan::checked_sub` to handle that case instead.
+    fn sub(self, other: CalendarSpan) -> CalendarSpan {
+        self.checked_sub(other).expect("overflow when subtracting calendar spans")
+    }
+}
+
+impl Neg for CalendarSpan {
+    type Output = CalendarSpan;
+
+    /// Negates the span, panicking if a part is at its type's minimum. Use
+    /// `CalendarSpan::checked_neg` to handle that case instead.
+    fn neg(self) -> CalendarSpan {
+        self.checked_neg().expect("overflow when negating a calendar span")
+    }
+}
+
+impl Display for CalendarSpan {
+    /// Formats the span as an ISO 8601 duration, e.g. `P1Y2M3D`, or `-P1Y2M3D` when no part is
+    /// positive. ISO 8601 has no way to write a span whose parts differ in sign, so those are
+    /// written with a sign on each negative part instead, e.g. `P1Y-2M3D`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.years <= 0 && self.months <= 0 && self.days <= 0 && *self != CalendarSpan::default() {
+            write!(f, "-P{}Y{}M{}D", self.years.unsigned_abs(), self.months.unsigned_abs(), self.days.unsigned_abs())
+        } else {
+            write!(f, "P{}Y{}M{}D", self.years, self.months, self.days)
+        }
+    }
+}
+
+
+/// A date in the proleptic Gregorian calendar, with no time of day or time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CivilDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl CivilDate {
+    /// Returns the date, or `None` if the month or day is out of range for that year.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<CivilDate> {
+        let days_in_month = CivilDate::days_in_month(year, month)?;
+        if day < 1 || day > days_in_month {
+            return None;
+        }
+        Some(CivilDate { year, month, day })
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    pub fn is_leap_year(year: i32) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+
+    /// Returns the number of days in the given month, from 1 for January to 12 for December, or
+    /// `None` if the month is out of range.
+    pub fn days_in_month(year: i32, month: u32) -> Option<u32> {
+        if month == 2 && CivilDate::is_leap_year(year) {
+            Some(29)
+        } else {
+            DAYS_PER_MONTH.get(month.checked_sub(1)? as usize).copied()
+        }
+    }
+
+    /// Returns the number of days since 1970-01-01, negative for earlier dates.
+    pub fn days_since_epoch(&self) -> i64 {
+        // Counts from 1 March so that the leap day falls at the end of the year.
+        let year = self.year as i64 - if self.month <= 2 { 1 } else { 0 };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month_from_march = (self.month as i64 + 9) % 12;
+        let day_of_year = (153 * month_from_march + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146097 + day_of_era - 719468
+    }
+
+    /// Returns the date the given number of days after 1970-01-01, or `None` if its year does
+    /// not fit in an `i32`.
+    pub fn from_days_since_epoch(days: i64) -> Option<CivilDate> {
+        let days = days.checked_add(719468)?;
+        let era = days.div_euclid(146097);
+        let day_of_era = days - era * 146097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_from_march = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+        let month = if month_from_march < 10 { month_from_march + 3 } else { month_from_march - 9 };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+        let year = i32::try_from(year).ok()?;
+        Some(CivilDate { year, month: month as u32, day: day as u32 })
+    }
+
+    /// Applies the span: the years and months move the month, clamping the day to the end of
+    /// the resulting month, and the days are then counted from there. Returns `None` if the
+    /// resulting year does not fit in an `i32`.
+    pub fn checked_add_span(&self, span: CalendarSpan) -> Option<CivilDate> {
+        let months = self.year as i64 * 12 + (self.month as i64 - 1)
+            + span.years as i64 * 12 + span.months as i64;
+        let year = i32::try_from(months.div_euclid(12)).ok()?;
+        let month = months.rem_euclid(12) as u32 + 1;
+        let day = self.day.min(CivilDate::days_in_month(year, month)?);
+        let date = CivilDate { year, month, day };
+        if span.days == 0 {
+            Some(date)
+        } else {
+            CivilDate::from_days_since_epoch(date.days_since_epoch().checked_add(span.days)?)
+        }
+    }
+
+    /// Applies the negated span, returning `None` if it cannot be negated or the resulting year
+    /// does not fit in an `i32`.
+    pub fn checked_sub_span(&self, span: CalendarSpan) -> Option<CivilDate> {
+        self.checked_add_span(span.checked_neg()?)
+    }
+}
+
+impl Add<CalendarSpan> for CivilDate {
+    type Output = CivilDate;
+
+    /// Applies the span, panicking if the resulting year does not fit in an `i32`. Use
+    /// `CivilDate::checked_add_span` to handle that case instead.
+    fn add(self, span: CalendarSpan) -> CivilDate {
+        self.checked_add_span(span).expect("overflow when adding a calendar span to a date")
+    }
+}
+
+impl Sub<CalendarSpan> for CivilDate {
+    type Output = CivilDate;
+
+    /// Applies the negated span, panicking if the resulting year does not fit in an `i32`. Use
+    /// `CivilDate::checked_sub_span` to handle that case instead.
+    fn sub(self, span: CalendarSpan) -> CivilDate {
+        self.checked_sub_span(span).expect("overflow when subtracting a calendar span from a date")
+    }
+}
+
+impl Sub for CivilDate {
+    type Output = Time;
+
+    /// Returns the exact number of days between two dates.
+    fn sub(self, other: CivilDate) -> Time {
+        Time::Days((self.days_since_epoch() - other.days_since_epoch()) as f64)
+    }
+}
+
+impl Display for CivilDate {
+    /// Formats the date as `YYYY-MM-DD`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+
+/*--------------------( Unit Tests )--------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_traits::Unit;
+
+    fn date(year: i32, month: u32, day: u32) -> CivilDate {
+        CivilDate::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(CivilDate::new(2024, 2, 29).is_some());
+        assert!(CivilDate::new(2023, 2, 29).is_none());
+        assert!(CivilDate::new(1900, 2, 29).is_none());
+        assert!(CivilDate::new(2000, 2, 29).is_some());
+        assert!(CivilDate::new(2024, 13, 1).is_none());
+        assert!(CivilDate::new(2024, 4, 31).is_none());
+        assert!(CivilDate::new(2024, 1, 0).is_none());
+        assert!(CivilDate::new(2024, 0, 1).is_none());
+    }
+
+    #[test]
+    fn test_days_in_month() {
+        assert_eq!(CivilDate::days_in_month(2024, 2), Some(29));
+        assert_eq!(CivilDate::days_in_month(2023, 2), Some(28));
+        assert_eq!(CivilDate::days_in_month(2023, 12), Some(31));
+        assert_eq!(CivilDate::days_in_month(2023, 0), None);
+        assert_eq!(CivilDate::days_in_month(2023, 13), None);
+    }
+
+    #[test]
+    fn test_epoch_days() {
+        assert_eq!(date(1970, 1, 1).days_since_epoch(), 0);
+        assert_eq!(date(2000, 3, 1).days_since_epoch(), 11017);
+        assert_eq!(date(1969, 12, 31).days_since_epoch(), -1);
+        for days in [-800_000, -1, 0, 59, 60, 11016, 11017, 19782, 2_932_896] {
+            assert_eq!(CivilDate::from_days_since_epoch(days).unwrap().days_since_epoch(), days);
+        }
+        assert_eq!(CivilDate::from_days_since_epoch(19782), Some(date(2024, 2, 29)));
+        let last = date(i32::MAX, 12, 31).days_since_epoch();
+        assert_eq!(CivilDate::from_days_since_epoch(last), Some(date(i32::MAX, 12, 31)));
+        assert_eq!(CivilDate::from_days_since_epoch(last + 1), None);
+        assert_eq!(CivilDate::from_days_since_epoch(i64::MAX), None);
+    }
+
+    #[test]
+    fn test_add_months_clamps() {
+        assert_eq!(date(2024, 1, 31) + CalendarSpan::months(1), date(2024, 2, 29));
+        assert_eq!(date(2023, 1, 31) + CalendarSpan::months(1), date(2023, 2, 28));
+        assert_eq!(date(2024, 3, 31) - CalendarSpan::months(1), date(2024, 2, 29));
+        assert_eq!(date(2024, 10, 31) + CalendarSpan::months(3), date(2025, 1, 31));
+        assert_eq!(date(2024, 1, 15) - CalendarSpan::months(13), date(2022, 12, 15));
+    }
+
+    #[test]
+    fn test_add_years() {
+        assert_eq!(date(2024, 2, 29) + CalendarSpan::years(1), date(2025, 2, 28));
+        assert_eq!(date(2024, 2, 29) + CalendarSpan::years(4), date(2028, 2, 29));
+        assert_eq!(date(2024, 2, 29) - CalendarSpan::years(100), date(1924, 2, 29));
+    }
+
+    #[test]
+    fn test_add_days() {
+        assert_eq!(date(2024, 2, 28) + CalendarSpan::days(1), date(2024, 2, 29));
+        assert_eq!(date(2023, 12, 31) + CalendarSpan::days(1), date(2024, 1, 1));
+        assert_eq!(date(2024, 3, 1) - CalendarSpan::days(1), date(2024, 2, 29));
+        assert_eq!(date(2024, 1, 31) + CalendarSpan::new(0, 1, 1), date(2024, 3, 1));
+        assert_eq!(date(2024, 1, 31) + CalendarSpan::new(1, 1, 0), date(2025, 2, 28));
+    }
+
+    #[test]
+    fn test_add_span_out_of_range() {
+        assert_eq!(date(i32::MAX, 12, 1).checked_add_span(CalendarSpan::months(1)), None);
+        assert_eq!(date(i32::MIN, 1, 1).checked_add_span(CalendarSpan::days(-1)), None);
+        assert_eq!(date(2024, 1, 1).checked_add_span(CalendarSpan::days(i64::MAX)), None);
+        assert_eq!(date(i32::MAX, 11, 30).checked_add_span(CalendarSpan::months(1)), Some(date(i32::MAX, 12, 30)));
+    }
+
+    #[test]
+    fn test_span_overflow() {
+        assert_eq!(CalendarSpan::years(i32::MAX).checked_add(CalendarSpan::years(1)), None);
+        assert_eq!(CalendarSpan::days(i64::MIN).checked_add(CalendarSpan::days(-1)), None);
+        assert_eq!(CalendarSpan::months(i32::MIN).checked_sub(CalendarSpan::months(1)), None);
+        // Subtraction does not go through negation, so i32::MIN can still be subtracted.
+        assert_eq!(CalendarSpan::years(-1).checked_sub(CalendarSpan::years(i32::MIN)), Some(CalendarSpan::years(i32::MAX)));
+        assert_eq!(CalendarSpan::new(1, 2, 3).checked_add(CalendarSpan::new(-1, 1, 1)), Some(CalendarSpan::new(0, 3, 4)));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when adding calendar spans")]
+    fn test_span_add_overflow_panics() {
+        let _ = CalendarSpan::days(i64::MAX) + CalendarSpan::days(1);
+    }
+
+    #[test]
+    fn test_sub_span_out_of_range() {
+        assert_eq!(CalendarSpan::years(i32::MIN).checked_neg(), None);
+        assert_eq!(CalendarSpan::days(i64::MIN).checked_neg(), None);
+        assert_eq!(CalendarSpan::new(1, -2, 3).checked_neg(), Some(CalendarSpan::new(-1, 2, -3)));
+        assert_eq!(date(2024, 1, 1).checked_sub_span(CalendarSpan::years(i32::MIN)), None);
+        assert_eq!(date(2024, 1, 1).checked_sub_span(CalendarSpan::days(i64::MIN)), None);
+        assert_eq!(date(2024, 3, 31).checked_sub_span(CalendarSpan::months(1)), Some(date(2024, 2, 29)));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when subtracting a calendar span from a date")]
+    fn test_sub_span_overflow_panics() {
+        let _ = date(2024, 1, 1) - CalendarSpan::years(i32::MIN);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when adding a calendar span to a date")]
+    fn test_add_span_overflow_panics() {
+        let _ = date(i32::MAX, 12, 31) + CalendarSpan::days(1);
+    }
+
+    #[test]
+    fn test_date_difference() {
+        assert_eq!(date(2025, 1, 1) - date(2024, 1, 1), Time::Days(366.0));
+        assert_eq!(date(2024, 1, 1) - date(2023, 1, 1), Time::Days(365.0));
+        assert_eq!(date(2024, 3, 1) - date(2024, 2, 1), Time::Days(29.0));
+    }
+
+    #[test]
+    fn test_span_ops() {
+        let span = CalendarSpan::new(1, 2, 3) + CalendarSpan::months(11);
+        assert_eq!(span, CalendarSpan::new(1, 13, 3));
+        assert_eq!(span - CalendarSpan::days(3), CalendarSpan::new(1, 13, 0));
+        assert_eq!(-span, CalendarSpan::new(-1, -13, -3));
+        assert_eq!(format!("{}", CalendarSpan::new(1, 2, 3)), "P1Y2M3D");
+        assert_eq!(format!("{}", -span), "-P1Y13M3D");
+        assert_eq!(format!("{}", CalendarSpan::months(-1)), "-P0Y1M0D");
+        assert_eq!(format!("{}", CalendarSpan::new(1, -2, 3)), "P1Y-2M3D");
+        assert_eq!(format!("{}", CalendarSpan::default()), "P0Y0M0D");
+        assert_eq!(format!("{}", CalendarSpan::years(i32::MIN)), "-P2147483648Y0M0D");
+        assert_eq!(format!("{}", date(987, 6, 5)), "0987-06-05");
+    }
+
+    #[test]
+    fn test_to_time_approx() {
+        assert_eq!(CalendarSpan::years(400).to_time_approx(), Time::Days(146097.0));
+        assert_eq!(CalendarSpan::days(10).to_time_approx(), Time::Days(10.0));
+        let month = CalendarSpan::months(1).to_time_approx();
+        assert!((month.to(TimeUnit::Days).value() - 365.2425 / 12.0).abs() < 1e-12);
+    }
+}
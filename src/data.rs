@@ -4,10 +4,13 @@
 //! The `Data` enum implements the `Unit` and `UnitConversion` traits, which offer the ability to 
 //! convert between different units of data sizes via the `to_base` and `to` methods.
 
-use std::ops::{Add, Sub, Mul, Div};
+use std::ops::{Mul, Div};
 use std::fmt::Display;
+use std::str::FromStr;
 use crate::unit_traits::UnitConversion;
 use crate::unit_traits::Unit;
+use crate::parse::{split_quantity, ParseUnitError};
+use crate::utils;
 
 
 #[derive(Debug)]
@@ -33,6 +36,16 @@ pub enum DataUnit {
 }
 
 
+/// The family of prefixes `Data::humanize` scales a data size with.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DataPrefix {
+    /// SI prefixes in powers of 1000: KB, MB, GB and TB.
+    Decimal,
+    /// IEC prefixes in powers of 1024: KiB, MiB, GiB and TiB.
+    Binary,
+}
+
+
 /// The `Data` enum represents data sizes in bytes, kilobytes, megabytes, etc.
 #[derive(Clone,Copy,Debug)]
 pub enum Data {
@@ -89,6 +102,35 @@ impl Data {
     pub fn GiB(x: f64) -> Data {
         Data::Gibibytes(x)
     }
+
+    /// Rescales the data size to the largest unit of the given prefix family that keeps the
+    /// value at or above 1, falling back to bytes for sizes under 1 KB (or KiB). Combined with a
+    /// precision, this gives `du -h` style output, e.g. `format!("{:.1}", x.humanize(DataPrefix::Binary))`
+    /// yields `"1.5 GiB"`.
+    pub fn humanize(&self, prefix: DataPrefix) -> Data {
+        let units = match prefix {
+            DataPrefix::Decimal => [
+                (Data::BYTES_PER_TB, DataUnit::Terabytes),
+                (Data::BYTES_PER_GB, DataUnit::Gigabytes),
+                (Data::BYTES_PER_MB, DataUnit::Megabytes),
+                (Data::BYTES_PER_KB, DataUnit::Kilobytes),
+            ],
+            DataPrefix::Binary => [
+                (Data::BYTES_PER_TBI, DataUnit::Tebibytes),
+                (Data::BYTES_PER_GBI, DataUnit::Gibibytes),
+                (Data::BYTES_PER_MBI, DataUnit::Mebibytes),
+                (Data::BYTES_PER_KBI, DataUnit::Kibibytes),
+            ],
+        };
+
+        let bytes = self.to_base();
+        for (factor, unit) in units {
+            if bytes.value().abs() >= factor {
+                return bytes.to(unit);
+            }
+        }
+        bytes
+    }
 }
 
 impl Unit for Data {
@@ -113,6 +155,21 @@ impl UnitConversion for Data {
     //! This trait provides methods for converting between different units of the same dimension.
     
     type Units = DataUnit;
+
+    /// Returns the unit the Data is expressed in.
+    fn unit(&self) -> DataUnit {
+        match self {
+            Data::Bytes(_) => DataUnit::Bytes,
+            Data::Kilobytes(_) => DataUnit::Kilobytes,
+            Data::Megabytes(_) => DataUnit::Megabytes,
+            Data::Gigabytes(_) => DataUnit::Gigabytes,
+            Data::Terabytes(_) => DataUnit::Terabytes,
+            Data::Kibibytes(_) => DataUnit::Kibibytes,
+            Data::Mebibytes(_) => DataUnit::Mebibytes,
+            Data::Gibibytes(_) => DataUnit::Gibibytes,
+            Data::Tebibytes(_) => DataUnit::Tebibytes,
+        }
+    }
     
     /// Converts a given instance of a Data enum into the base variant, `Data::Bytes`.
     fn to_base(&self) -> Data {
@@ -150,77 +207,6 @@ impl UnitConversion for Data {
     }
 }
 
-impl PartialEq for Data {
-    fn eq(&self, other: &Data) -> bool {
-        let a = self.to_base().value();
-        let b = other.to_base().value();
-        (a - b).abs() < f64::EPSILON
-    }
-}
-
-impl PartialOrd for Data {
-    fn partial_cmp(&self, other: &Data) -> Option<std::cmp::Ordering> {
-        let a = self.to_base().value();
-        let b = other.to_base().value();
-        (a - b).partial_cmp(&0.0)
-    }
-}
-
-
-impl Add for Data {
-    type Output = Data;
-
-    fn add(self, other: Data) -> Data {
-        let a = self.to_base();
-        let b = other.to_base();
-        let bytes = match a {
-            Data::Bytes(x) => match b {
-                Data::Bytes(y) => Data::Bytes(x + y),
-                _ => panic!("unsupported operation"),
-            },
-            _ => panic!("expected self to be of variant Data::Bytes"),
-        };
-        match self {
-            Data::Bytes(_) => bytes,
-            Data::Kilobytes(_) => bytes.to(DataUnit::Kilobytes),
-            Data::Megabytes(_) => bytes.to(DataUnit::Megabytes),
-            Data::Gigabytes(_) => bytes.to(DataUnit::Gigabytes),
-            Data::Terabytes(_) => bytes.to(DataUnit::Terabytes),
-            Data::Kibibytes(_) => bytes.to(DataUnit::Kibibytes),
-            Data::Mebibytes(_) => bytes.to(DataUnit::Mebibytes),
-            Data::Gibibytes(_) => bytes.to(DataUnit::Gibibytes),
-            Data::Tebibytes(_) => bytes.to(DataUnit::Tebibytes),
-        }
-    }
-}
-
-impl Sub for Data {
-    type Output = Data;
-
-    fn sub(self, other: Data) -> Data {
-        let a = self.to_base();
-        let b = other.to_base();
-        let bytes = match a {
-            Data::Bytes(x) => match b {
-                Data::Bytes(y) => Data::Bytes(x - y),
-                _ => panic!("unsupported operation"),
-            },
-            _ => panic!("expected self to be of variant Data::Bytes"),
-        };
-        match self {
-            Data::Bytes(_) => bytes,
-            Data::Kilobytes(_) => bytes.to(DataUnit::Kilobytes),
-            Data::Megabytes(_) => bytes.to(DataUnit::Megabytes),
-            Data::Gigabytes(_) => bytes.to(DataUnit::Gigabytes),
-            Data::Terabytes(_) => bytes.to(DataUnit::Terabytes),
-            Data::Kibibytes(_) => bytes.to(DataUnit::Kibibytes),
-            Data::Mebibytes(_) => bytes.to(DataUnit::Mebibytes),
-            Data::Gibibytes(_) => bytes.to(DataUnit::Gibibytes),
-            Data::Tebibytes(_) => bytes.to(DataUnit::Tebibytes),
-        }
-    }
-}
-
 
 impl Mul<f64> for Data {
     type Output = Data;
@@ -281,17 +267,54 @@ impl Div<f64> for Data {
 
 impl Display for Data {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self {
-            Data::Bytes(x) => write!(f, "{} bytes", x),
-            Data::Kilobytes(x) => write!(f, "{} KB", x),
-            Data::Megabytes(x) => write!(f, "{} MB", x),
-            Data::Gigabytes(x) => write!(f, "{} GB", x),
-            Data::Terabytes(x) => write!(f, "{} TB", x),
-            Data::Kibibytes(x) => write!(f, "{} KiB", x),
-            Data::Mebibytes(x) => write!(f, "{} MiB", x),
-            Data::Gibibytes(x) => write!(f, "{} GiB", x),
-            Data::Tebibytes(x) => write!(f, "{} TiB", x),
-        }
+        let (x, symbol, name) = match self {
+            Data::Bytes(x) => (x, "bytes", "bytes"),
+            Data::Kilobytes(x) => (x, "KB", "kilobytes"),
+            Data::Megabytes(x) => (x, "MB", "megabytes"),
+            Data::Gigabytes(x) => (x, "GB", "gigabytes"),
+            Data::Terabytes(x) => (x, "TB", "terabytes"),
+            Data::Kibibytes(x) => (x, "KiB", "kibibytes"),
+            Data::Mebibytes(x) => (x, "MiB", "mebibytes"),
+            Data::Gibibytes(x) => (x, "GiB", "gibibytes"),
+            Data::Tebibytes(x) => (x, "TiB", "tebibytes"),
+        };
+        utils::fmt_quantity(f, *x, symbol, name)
+    }
+}
+
+
+impl FromStr for Data {
+    type Err = ParseUnitError;
+
+    /// Parses strings such as `"512 MiB"`, `"1.5GB"` or `"20 kilobytes"`. Unit symbols are case
+    /// sensitive, so `"Mb"` (megabits) is rejected rather than read as megabytes, while
+    /// spelled-out unit names are not.
+    fn from_str(s: &str) -> Result<Data, ParseUnitError> {
+        let (x, unit) = split_quantity(s)?;
+        let data = match unit {
+            "B" => Data::Bytes(x),
+            "KB" | "kB" => Data::Kilobytes(x),
+            "MB" => Data::Megabytes(x),
+            "GB" => Data::Gigabytes(x),
+            "TB" => Data::Terabytes(x),
+            "KiB" => Data::Kibibytes(x),
+            "MiB" => Data::Mebibytes(x),
+            "GiB" => Data::Gibibytes(x),
+            "TiB" => Data::Tebibytes(x),
+            _ => match unit.to_lowercase().as_str() {
+                "byte" | "bytes" => Data::Bytes(x),
+                "kilobyte" | "kilobytes" => Data::Kilobytes(x),
+                "megabyte" | "megabytes" => Data::Megabytes(x),
+                "gigabyte" | "gigabytes" => Data::Gigabytes(x),
+                "terabyte" | "terabytes" => Data::Terabytes(x),
+                "kibibyte" | "kibibytes" => Data::Kibibytes(x),
+                "mebibyte" | "mebibytes" => Data::Mebibytes(x),
+                "gibibyte" | "gibibytes" => Data::Gibibytes(x),
+                "tebibyte" | "tebibytes" => Data::Tebibytes(x),
+                _ => return Err(ParseUnitError::UnknownUnit(unit.to_string())),
+            },
+        };
+        Ok(data)
     }
 }
 
@@ -427,4 +450,74 @@ mod tests {
         let doubled_2 = 2.0 * memory_2;
         assert_eq!(doubled_2, Data::MiB(4096.0));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("512 MiB".parse::<Data>(), Ok(Data::Mebibytes(512.0)));
+        assert_eq!("1.5GB".parse::<Data>(), Ok(Data::Gigabytes(1.5)));
+        assert_eq!("  20 kilobytes ".parse::<Data>(), Ok(Data::Kilobytes(20.0)));
+        assert_eq!("3 Tebibytes".parse::<Data>(), Ok(Data::Tebibytes(3.0)));
+        assert_eq!("100B".parse::<Data>(), Ok(Data::Bytes(100.0)));
+        match "2 KiB".parse::<Data>() {
+            Ok(Data::Kibibytes(x)) => assert_eq!(x, 2.0),
+            _ => panic!("expected Data::Kibibytes variant"),
+        }
+    }
+
+    #[test]
+    fn test_from_str_round_trip() {
+        let all = [
+            Data::Bytes(1024.0), Data::Kilobytes(1.5), Data::Megabytes(2.0), Data::Gigabytes(0.25),
+            Data::Terabytes(4.0), Data::Kibibytes(8.0), Data::Mebibytes(512.0),
+            Data::Gibibytes(16.0), Data::Tebibytes(1.0),
+        ];
+        for data in all {
+            let parsed: Data = data.to_string().parse().unwrap();
+            assert_eq!(format!("{}", parsed), format!("{}", data));
+        }
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        assert_eq!("12 Mb".parse::<Data>(), Err(ParseUnitError::UnknownUnit("Mb".to_string())));
+        assert_eq!("12 mib".parse::<Data>(), Err(ParseUnitError::UnknownUnit("mib".to_string())));
+        assert_eq!("twelve MB".parse::<Data>(), Err(ParseUnitError::InvalidNumber("twelve".to_string())));
+        assert_eq!("12".parse::<Data>(), Err(ParseUnitError::MissingUnit));
+        assert_eq!("".parse::<Data>(), Err(ParseUnitError::Empty));
+    }
+
+    #[test]
+    fn test_humanize() {
+        let x = Data::Bytes(1048576.0);
+        match x.humanize(DataPrefix::Binary) {
+            Data::Mebibytes(v) => assert_eq!(v, 1.0),
+            _ => panic!("expected Data::Mebibytes variant"),
+        }
+        match x.humanize(DataPrefix::Decimal) {
+            Data::Megabytes(v) => assert!((v - 1.048576).abs() < 1e-12),
+            _ => panic!("expected Data::Megabytes variant"),
+        }
+
+        assert_eq!(format!("{:.1}", Data::GiB(1536.0).humanize(DataPrefix::Binary)), "1.5 TiB");
+        assert_eq!(format!("{:.2}", Data::KB(999.0).humanize(DataPrefix::Decimal)), "999.00 KB");
+        assert_eq!(format!("{:.0}", Data::Kibibytes(0.5).humanize(DataPrefix::Binary)), "512 bytes");
+        assert_eq!(format!("{:.1}", Data::MB(-2500.0).humanize(DataPrefix::Decimal)), "-2.5 GB");
+        assert_eq!(format!("{}", Data::Bytes(0.0).humanize(DataPrefix::Decimal)), "0 bytes");
+        assert_eq!(format!("{:.1}", Data::Terabytes(4000.0).humanize(DataPrefix::Decimal)), "4000.0 TB");
+    }
+
+    #[test]
+    fn test_display_precision() {
+        assert_eq!(format!("{}", Data::MB(1.5)), "1.5 MB");
+        assert_eq!(format!("{:.2}", Data::MB(1.005)), "1.00 MB");
+        assert_eq!(format!("{:.3}", Data::GiB(2.0)), "2.000 GiB");
+    }
+
+    #[test]
+    fn test_display_format() {
+        assert_eq!(format!("{:#.1}", Data::MiB(1.25)), "1.2 mebibytes");
+        assert_eq!(format!("{:>10}", Data::GB(4.0)), "      4 GB");
+        assert_eq!(format!("{:#}", Data::Bytes(512.0)), "512 bytes");
+        assert_eq!(format!("{:.1}", Data::Bytes(1536.0).humanize(DataPrefix::Binary)), "1.5 KiB");
+    }
+}
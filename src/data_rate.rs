@@ -1,7 +1,14 @@
+use std::ops::{Mul, Div};
+use std::fmt::Display;
+use std::str::FromStr;
 use crate::prelude::UnitConversion;
 use crate::prelude::Unit;
-use crate::Data;
+use crate::parse::{split_quantity, ParseUnitError};
+use crate::data::{Data, DataUnit};
 use crate::Time;
+use crate::quantity::Quantity;
+use crate::dimension;
+use crate::utils;
 
 #[derive(Clone,Copy,Debug)]
 #[allow(non_camel_case_types)]
@@ -48,6 +55,12 @@ pub enum DataRate {
     TiBps(f64),
 }
 
+impl DataRate {
+    /// Every factor in this module is derived from this and the `Data::BYTES_PER_*` constants, so
+    /// a decimal prefix always means a power of 1000 and a binary prefix a power of 1024.
+    pub const BITS_PER_BYTE: f64 = 8.0;
+}
+
 impl Unit for DataRate {
     fn value(&self) -> f64 {
         match self {
@@ -76,71 +89,471 @@ impl Unit for DataRate {
 impl UnitConversion for DataRate {
     type Units = DataRateUnit;
 
+    /// Returns the unit the DataRate is expressed in.
+    fn unit(&self) -> DataRateUnit {
+        match self {
+            DataRate::bps(_) => DataRateUnit::bps,
+            DataRate::Kbps(_) => DataRateUnit::Kbps,
+            DataRate::KBps(_) => DataRateUnit::KBps,
+            DataRate::Kibps(_) => DataRateUnit::Kibps,
+            DataRate::KiBps(_) => DataRateUnit::KiBps,
+            DataRate::Mbps(_) => DataRateUnit::Mbps,
+            DataRate::MBps(_) => DataRateUnit::MBps,
+            DataRate::Mibps(_) => DataRateUnit::Mibps,
+            DataRate::MiBps(_) => DataRateUnit::MiBps,
+            DataRate::Gbps(_) => DataRateUnit::Gbps,
+            DataRate::GBps(_) => DataRateUnit::GBps,
+            DataRate::Gibps(_) => DataRateUnit::Gibps,
+            DataRate::GiBps(_) => DataRateUnit::GiBps,
+            DataRate::Tbps(_) => DataRateUnit::Tbps,
+            DataRate::TBps(_) => DataRateUnit::TBps,
+            DataRate::Tibps(_) => DataRateUnit::Tibps,
+            DataRate::TiBps(_) => DataRateUnit::TiBps,
+        }
+    }
+
     fn to_base(&self) -> DataRate {
         match self {
             DataRate::bps(x)   => DataRate::bps(*x),
-            DataRate::Kbps(x)  => DataRate::bps(x * 1000.0),
-            DataRate::KBps(x)  => DataRate::bps(x * 8000.0),
-            DataRate::Kibps(x) => DataRate::bps(x * 1024.0 * 1000.0),
-            DataRate::KiBps(x) => DataRate::bps(x * 1024.0 * 8.0),
-            DataRate::Mbps(x)  => DataRate::bps(x * 1000.0 * 1000.0),
-            DataRate::MBps(x)  => DataRate::bps(x * 8000.0 * 1000.0),
-            DataRate::Mibps(x) => DataRate::bps(x * 1024.0 * 1000.0 * 1000.0),
-            DataRate::MiBps(x) => DataRate::bps(x * 1024.0 * 8.0 * 1000.0),
-            DataRate::Gbps(x)  => DataRate::bps(x * 1000.0 * 1000.0 * 1000.0),
-            DataRate::GBps(x)  => DataRate::bps(x * 8000.0 * 1000.0 * 1000.0),
-            DataRate::Gibps(x) => DataRate::bps(x * 1024.0 * 1000.0 * 1000.0 * 1000.0),
-            DataRate::GiBps(x) => DataRate::bps(x * 1024.0 * 8.0 * 1000.0 * 1000.0),
-            DataRate::Tbps(x)  => DataRate::bps(x * 1000.0 * 1000.0 * 1000.0 * 1000.0),
-            DataRate::TBps(x)  => DataRate::bps(x * 8000.0 * 1000.0 * 1000.0 * 1000.0),
-            DataRate::Tibps(x) => DataRate::bps(x * 1024.0 * 1000.0 * 1000.0 * 1000.0 * 1000.0),
-            DataRate::TiBps(x) => DataRate::bps(x * 1024.0 * 8.0 * 1000.0 * 1000.0 * 1000.0),
+            DataRate::Kbps(x)  => DataRate::bps(x * Data::BYTES_PER_KB),
+            DataRate::KBps(x)  => DataRate::bps(x * Data::BYTES_PER_KB * DataRate::BITS_PER_BYTE),
+            DataRate::Kibps(x) => DataRate::bps(x * Data::BYTES_PER_KBI),
+            DataRate::KiBps(x) => DataRate::bps(x * Data::BYTES_PER_KBI * DataRate::BITS_PER_BYTE),
+            DataRate::Mbps(x)  => DataRate::bps(x * Data::BYTES_PER_MB),
+            DataRate::MBps(x)  => DataRate::bps(x * Data::BYTES_PER_MB * DataRate::BITS_PER_BYTE),
+            DataRate::Mibps(x) => DataRate::bps(x * Data::BYTES_PER_MBI),
+            DataRate::MiBps(x) => DataRate::bps(x * Data::BYTES_PER_MBI * DataRate::BITS_PER_BYTE),
+            DataRate::Gbps(x)  => DataRate::bps(x * Data::BYTES_PER_GB),
+            DataRate::GBps(x)  => DataRate::bps(x * Data::BYTES_PER_GB * DataRate::BITS_PER_BYTE),
+            DataRate::Gibps(x) => DataRate::bps(x * Data::BYTES_PER_GBI),
+            DataRate::GiBps(x) => DataRate::bps(x * Data::BYTES_PER_GBI * DataRate::BITS_PER_BYTE),
+            DataRate::Tbps(x)  => DataRate::bps(x * Data::BYTES_PER_TB),
+            DataRate::TBps(x)  => DataRate::bps(x * Data::BYTES_PER_TB * DataRate::BITS_PER_BYTE),
+            DataRate::Tibps(x) => DataRate::bps(x * Data::BYTES_PER_TBI),
+            DataRate::TiBps(x) => DataRate::bps(x * Data::BYTES_PER_TBI * DataRate::BITS_PER_BYTE),
         }
     }
 
     fn to(&self, other: DataRateUnit) -> DataRate {
         let base = self.to_base().value();
         match other {
-            DataRateUnit::bps => DataRate::bps(base),
-            DataRateUnit::Kbps => DataRate::Kbps(base / 1000.0),
-            DataRateUnit::KBps => DataRate::KBps(base / 8000.0),
-            DataRateUnit::Kibps => DataRate::Kibps(base / (1024.0 * 1000.0)),
-            DataRateUnit::KiBps => DataRate::KiBps(base / (1024.0 * 8.0)),
-            DataRateUnit::Mbps => DataRate::Mbps(base / (1000.0 * 1000.0)),
-            DataRateUnit::MBps => DataRate::MBps(base / (8000.0 * 1000.0)),
-            DataRateUnit::Mibps => DataRate::Mibps(base / (1024.0 * 1000.0 * 1000.0)),
-            DataRateUnit::MiBps => DataRate::MiBps(base / (1024.0 * 8.0 * 1000.0)),
-            DataRateUnit::Gbps => DataRate::Gbps(base / (1000.0 * 1000.0 * 1000.0)),
-            DataRateUnit::GBps => DataRate::GBps(base / (8000.0 * 1000.0 * 1000.0)),
-            DataRateUnit::Gibps => DataRate::Gibps(base / (1024.0 * 1000.0 * 1000.0 * 1000.0)),
-            DataRateUnit::GiBps => DataRate::GiBps(base / (1024.0 * 8.0 * 1000.0 * 1000.0)),
-            DataRateUnit::Tbps => DataRate::Tbps(base / (1000.0 * 1000.0 * 1000.0 * 1000.0)),
-            DataRateUnit::TBps => DataRate::TBps(base / (8000.0 * 1000.0 * 1000.0 * 1000.0)),
-            DataRateUnit::Tibps => DataRate::Tibps(base / (1024.0 * 1000.0 * 1000.0 * 1000.0 * 1000.0)),
-            DataRateUnit::TiBps => DataRate::TiBps(base / (1024.0 * 8.0 * 1000.0 * 1000.0 * 1000.0)),
+            DataRateUnit::bps   => DataRate::bps(base),
+            DataRateUnit::Kbps  => DataRate::Kbps(base / Data::BYTES_PER_KB),
+            DataRateUnit::KBps  => DataRate::KBps(base / (Data::BYTES_PER_KB * DataRate::BITS_PER_BYTE)),
+            DataRateUnit::Kibps => DataRate::Kibps(base / Data::BYTES_PER_KBI),
+            DataRateUnit::KiBps => DataRate::KiBps(base / (Data::BYTES_PER_KBI * DataRate::BITS_PER_BYTE)),
+            DataRateUnit::Mbps  => DataRate::Mbps(base / Data::BYTES_PER_MB),
+            DataRateUnit::MBps  => DataRate::MBps(base / (Data::BYTES_PER_MB * DataRate::BITS_PER_BYTE)),
+            DataRateUnit::Mibps => DataRate::Mibps(base / Data::BYTES_PER_MBI),
+            DataRateUnit::MiBps => DataRate::MiBps(base / (Data::BYTES_PER_MBI * DataRate::BITS_PER_BYTE)),
+            DataRateUnit::Gbps  => DataRate::Gbps(base / Data::BYTES_PER_GB),
+            DataRateUnit::GBps  => DataRate::GBps(base / (Data::BYTES_PER_GB * DataRate::BITS_PER_BYTE)),
+            DataRateUnit::Gibps => DataRate::Gibps(base / Data::BYTES_PER_GBI),
+            DataRateUnit::GiBps => DataRate::GiBps(base / (Data::BYTES_PER_GBI * DataRate::BITS_PER_BYTE)),
+            DataRateUnit::Tbps  => DataRate::Tbps(base / Data::BYTES_PER_TB),
+            DataRateUnit::TBps  => DataRate::TBps(base / (Data::BYTES_PER_TB * DataRate::BITS_PER_BYTE)),
+            DataRateUnit::Tibps => DataRate::Tibps(base / Data::BYTES_PER_TBI),
+            DataRateUnit::TiBps => DataRate::TiBps(base / (Data::BYTES_PER_TBI * DataRate::BITS_PER_BYTE)),
         }
     }
 }
 
-impl std::ops::Div<Time> for Data {
+
+impl Mul<f64> for DataRate {
     type Output = DataRate;
 
-    fn div(self, rhs: Time) -> DataRate {
-        let sec = rhs.to_base().value();
+    fn mul(self, other: f64) -> DataRate {
+        match self {
+            DataRate::bps(x)   => DataRate::bps(x * other),
+            DataRate::Kbps(x)  => DataRate::Kbps(x * other),
+            DataRate::KBps(x)  => DataRate::KBps(x * other),
+            DataRate::Kibps(x) => DataRate::Kibps(x * other),
+            DataRate::KiBps(x) => DataRate::KiBps(x * other),
+            DataRate::Mbps(x)  => DataRate::Mbps(x * other),
+            DataRate::MBps(x)  => DataRate::MBps(x * other),
+            DataRate::Mibps(x) => DataRate::Mibps(x * other),
+            DataRate::MiBps(x) => DataRate::MiBps(x * other),
+            DataRate::Gbps(x)  => DataRate::Gbps(x * other),
+            DataRate::GBps(x)  => DataRate::GBps(x * other),
+            DataRate::Gibps(x) => DataRate::Gibps(x * other),
+            DataRate::GiBps(x) => DataRate::GiBps(x * other),
+            DataRate::Tbps(x)  => DataRate::Tbps(x * other),
+            DataRate::TBps(x)  => DataRate::TBps(x * other),
+            DataRate::Tibps(x) => DataRate::Tibps(x * other),
+            DataRate::TiBps(x) => DataRate::TiBps(x * other),
+        }
+    }
+}
+
+impl Mul<DataRate> for f64 {
+    type Output = DataRate;
 
+    fn mul(self, other: DataRate) -> DataRate {
+        match other {
+            DataRate::bps(x)   => DataRate::bps(x * self),
+            DataRate::Kbps(x)  => DataRate::Kbps(x * self),
+            DataRate::KBps(x)  => DataRate::KBps(x * self),
+            DataRate::Kibps(x) => DataRate::Kibps(x * self),
+            DataRate::KiBps(x) => DataRate::KiBps(x * self),
+            DataRate::Mbps(x)  => DataRate::Mbps(x * self),
+            DataRate::MBps(x)  => DataRate::MBps(x * self),
+            DataRate::Mibps(x) => DataRate::Mibps(x * self),
+            DataRate::MiBps(x) => DataRate::MiBps(x * self),
+            DataRate::Gbps(x)  => DataRate::Gbps(x * self),
+            DataRate::GBps(x)  => DataRate::GBps(x * self),
+            DataRate::Gibps(x) => DataRate::Gibps(x * self),
+            DataRate::GiBps(x) => DataRate::GiBps(x * self),
+            DataRate::Tbps(x)  => DataRate::Tbps(x * self),
+            DataRate::TBps(x)  => DataRate::TBps(x * self),
+            DataRate::Tibps(x) => DataRate::Tibps(x * self),
+            DataRate::TiBps(x) => DataRate::TiBps(x * self),
+        }
+    }
+}
+
+impl Div<f64> for DataRate {
+    type Output = DataRate;
+
+    fn div(self, other: f64) -> DataRate {
         match self {
-            Data::Bytes(x) => DataRate::bps(8.0 * x / sec),
-            Data::Kilobytes(x) => DataRate::KBps(x / sec),
-            Data::Megabytes(x) => DataRate::MBps(x / sec),
-            Data::Gigabytes(x) => DataRate::GBps(x / sec),
-            Data::Terabytes(x) => DataRate::TBps(x / sec),
-            Data::Kibibytes(x) => DataRate::KiBps(x / sec),
-            Data::Mebibytes(x) => DataRate::MiBps(x / sec),
-            Data::Gibibytes(x) => DataRate::GiBps(x / sec),
-            Data::Tebibytes(x) => DataRate::TiBps(x / sec),
+            DataRate::bps(x)   => DataRate::bps(x / other),
+            DataRate::Kbps(x)  => DataRate::Kbps(x / other),
+            DataRate::KBps(x)  => DataRate::KBps(x / other),
+            DataRate::Kibps(x) => DataRate::Kibps(x / other),
+            DataRate::KiBps(x) => DataRate::KiBps(x / other),
+            DataRate::Mbps(x)  => DataRate::Mbps(x / other),
+            DataRate::MBps(x)  => DataRate::MBps(x / other),
+            DataRate::Mibps(x) => DataRate::Mibps(x / other),
+            DataRate::MiBps(x) => DataRate::MiBps(x / other),
+            DataRate::Gbps(x)  => DataRate::Gbps(x / other),
+            DataRate::GBps(x)  => DataRate::GBps(x / other),
+            DataRate::Gibps(x) => DataRate::Gibps(x / other),
+            DataRate::GiBps(x) => DataRate::GiBps(x / other),
+            DataRate::Tbps(x)  => DataRate::Tbps(x / other),
+            DataRate::TBps(x)  => DataRate::TBps(x / other),
+            DataRate::Tibps(x) => DataRate::Tibps(x / other),
+            DataRate::TiBps(x) => DataRate::TiBps(x / other),
         }
     }
 }
 
+impl Display for DataRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (x, symbol, name) = match self {
+            DataRate::bps(x)   => (x, "bps", "bits per second"),
+            DataRate::Kbps(x)  => (x, "Kbps", "kilobits per second"),
+            DataRate::KBps(x)  => (x, "KBps", "kilobytes per second"),
+            DataRate::Kibps(x) => (x, "Kibps", "kibibits per second"),
+            DataRate::KiBps(x) => (x, "KiBps", "kibibytes per second"),
+            DataRate::Mbps(x)  => (x, "Mbps", "megabits per second"),
+            DataRate::MBps(x)  => (x, "MBps", "megabytes per second"),
+            DataRate::Mibps(x) => (x, "Mibps", "mebibits per second"),
+            DataRate::MiBps(x) => (x, "MiBps", "mebibytes per second"),
+            DataRate::Gbps(x)  => (x, "Gbps", "gigabits per second"),
+            DataRate::GBps(x)  => (x, "GBps", "gigabytes per second"),
+            DataRate::Gibps(x) => (x, "Gibps", "gibibits per second"),
+            DataRate::GiBps(x) => (x, "GiBps", "gibibytes per second"),
+            DataRate::Tbps(x)  => (x, "Tbps", "terabits per second"),
+            DataRate::TBps(x)  => (x, "TBps", "terabytes per second"),
+            DataRate::Tibps(x) => (x, "Tibps", "tebibits per second"),
+            DataRate::TiBps(x) => (x, "TiBps", "tebibytes per second"),
+        };
+        utils::fmt_quantity(f, *x, symbol, name)
+    }
+}
+
+impl FromStr for DataRate {
+    type Err = ParseUnitError;
+
+    /// Parses strings such as `"100 Mbps"`, `"12.5 MB/s"`, `"1 Gib/s"` or `"10 megabits per
+    /// second"`. Unit symbols are case sensitive because it is the case of the `b` that tells bits
+    /// (`Mbps`) from bytes (`MBps`), while spelled-out unit names are not.
+    fn from_str(s: &str) -> Result<DataRate, ParseUnitError> {
+        let (x, unit) = split_quantity(s)?;
+        let prefix = unit.strip_suffix("ps").or_else(|| unit.strip_suffix("/s"));
+        let rate = match prefix {
+            Some("b")           => DataRate::bps(x),
+            Some("Kb" | "kb")   => DataRate::Kbps(x),
+            Some("KB" | "kB")   => DataRate::KBps(x),
+            Some("Kib")         => DataRate::Kibps(x),
+            Some("KiB")         => DataRate::KiBps(x),
+            Some("Mb")          => DataRate::Mbps(x),
+            Some("MB")          => DataRate::MBps(x),
+            Some("Mib")         => DataRate::Mibps(x),
+            Some("MiB")         => DataRate::MiBps(x),
+            Some("Gb")          => DataRate::Gbps(x),
+            Some("GB")          => DataRate::GBps(x),
+            Some("Gib")         => DataRate::Gibps(x),
+            Some("GiB")         => DataRate::GiBps(x),
+            Some("Tb")          => DataRate::Tbps(x),
+            Some("TB")          => DataRate::TBps(x),
+            Some("Tib")         => DataRate::Tibps(x),
+            Some("TiB")         => DataRate::TiBps(x),
+            _ => {
+                let name = unit.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+                match name.strip_suffix(" per second") {
+                    Some("bit" | "bits")           => DataRate::bps(x),
+                    Some("kilobit" | "kilobits")   => DataRate::Kbps(x),
+                    Some("kilobyte" | "kilobytes") => DataRate::KBps(x),
+                    Some("kibibit" | "kibibits")   => DataRate::Kibps(x),
+                    Some("kibibyte" | "kibibytes") => DataRate::KiBps(x),
+                    Some("megabit" | "megabits")   => DataRate::Mbps(x),
+                    Some("megabyte" | "megabytes") => DataRate::MBps(x),
+                    Some("mebibit" | "mebibits")   => DataRate::Mibps(x),
+                    Some("mebibyte" | "mebibytes") => DataRate::MiBps(x),
+                    Some("gigabit" | "gigabits")   => DataRate::Gbps(x),
+                    Some("gigabyte" | "gigabytes") => DataRate::GBps(x),
+                    Some("gibibit" | "gibibits")   => DataRate::Gibps(x),
+                    Some("gibibyte" | "gibibytes") => DataRate::GiBps(x),
+                    Some("terabit" | "terabits")   => DataRate::Tbps(x),
+                    Some("terabyte" | "terabytes") => DataRate::TBps(x),
+                    Some("tebibit" | "tebibits")   => DataRate::Tibps(x),
+                    Some("tebibyte" | "tebibytes") => DataRate::TiBps(x),
+                    _ => return Err(ParseUnitError::UnknownUnit(unit.to_string())),
+                }
+            }
+        };
+        Ok(rate)
+    }
+}
+
+impl std::ops::Div<Time> for Data {
+    type Output = DataRate;
+
+    /// Divides an amount of data by a time. Bytes produce bits per second and every other unit
+    /// produces the byte rate with the same prefix, e.g. `Data::Gigabytes` produces `GBps`.
+    fn div(self, rhs: Time) -> DataRate {
+        let unit = match self {
+            Data::Bytes(_) => DataRateUnit::bps,
+            Data::Kilobytes(_) => DataRateUnit::KBps,
+            Data::Megabytes(_) => DataRateUnit::MBps,
+            Data::Gigabytes(_) => DataRateUnit::GBps,
+            Data::Terabytes(_) => DataRateUnit::TBps,
+            Data::Kibibytes(_) => DataRateUnit::KiBps,
+            Data::Mebibytes(_) => DataRateUnit::MiBps,
+            Data::Gibibytes(_) => DataRateUnit::GiBps,
+            Data::Tebibytes(_) => DataRateUnit::TiBps,
+        };
+        let rate: Quantity<dimension::DataRate> = Quantity::from(self) / Quantity::from(rhs);
+        DataRate::from(rate).to(unit)
+    }
+}
+
+
+impl std::ops::Mul<Time> for DataRate {
+    type Output = Data;
+
+    /// Multiplies a data rate by a time, producing the amount of data transferred. Bit rates
+    /// produce the byte unit with the same prefix, e.g. `Gbps` produces `Data::Gigabytes`.
+    fn mul(self, rhs: Time) -> Data {
+        let unit = match self {
+            DataRate::bps(_) => DataUnit::Bytes,
+            DataRate::Kbps(_) | DataRate::KBps(_) => DataUnit::Kilobytes,
+            DataRate::Kibps(_) | DataRate::KiBps(_) => DataUnit::Kibibytes,
+            DataRate::Mbps(_) | DataRate::MBps(_) => DataUnit::Megabytes,
+            DataRate::Mibps(_) | DataRate::MiBps(_) => DataUnit::Mebibytes,
+            DataRate::Gbps(_) | DataRate::GBps(_) => DataUnit::Gigabytes,
+            DataRate::Gibps(_) | DataRate::GiBps(_) => DataUnit::Gibibytes,
+            DataRate::Tbps(_) | DataRate::TBps(_) => DataUnit::Terabytes,
+            DataRate::Tibps(_) | DataRate::TiBps(_) => DataUnit::Tebibytes,
+        };
+        let data: Quantity<dimension::Data> = Quantity::from(self) * Quantity::from(rhs);
+        Data::from(data).to(unit)
+    }
+}
+
+impl std::ops::Mul<DataRate> for Time {
+    type Output = Data;
+
+    fn mul(self, rhs: DataRate) -> Data {
+        rhs * self
+    }
+}
+
+impl std::ops::Div<DataRate> for Data {
+    type Output = Time;
 
+    /// Divides an amount of data by a data rate, producing the transfer time in seconds.
+    fn div(self, rhs: DataRate) -> Time {
+        let time: Quantity<dimension::Time> = Quantity::from(self) / Quantity::from(rhs);
+        Time::from(time)
+    }
+}
+
+
+/*--------------------( Unit Tests )--------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(DataRate::Gbps(1.0), DataRate::Mbps(1000.0));
+        assert_eq!(DataRate::MBps(1.0), DataRate::Mbps(8.0));
+        assert_eq!(DataRate::KiBps(1.0), DataRate::bps(8192.0));
+        assert_eq!(DataRate::bps(1.233e8).to(DataRateUnit::Tbps), DataRate::bps(1.233e8));
+        assert_eq!(DataRate::TiBps(3.3), DataRate::GiBps(3.3 * 1024.0));
+    }
+
+    #[test]
+    fn test_cmp() {
+        // Measured throughput against an SLA
+        let sla = DataRate::Gbps(1.0);
+        let measured = DataRate::MBps(120.0);
+        assert!(measured < sla);
+        assert!(DataRate::MBps(126.0) > sla);
+        assert!(DataRate::GBps(1.0) > DataRate::Gbps(1.0));
+    }
+
+    #[test]
+    fn test_add_same_units() {
+        let x = DataRate::Gbps(2.5);
+        let y = DataRate::Gbps(7.5);
+        match x + y {
+            DataRate::Gbps(z) => assert_eq!(z, 10.0),
+            _ => panic!("expected DataRate::Gbps variant"),
+        }
+    }
 
+    #[test]
+    fn test_add_different_units() {
+        // Summing link rates keeps the unit of the left operand
+        let links = DataRate::Gbps(1.0) + DataRate::Mbps(500.0) + DataRate::MBps(125.0);
+        match links {
+            DataRate::Gbps(z) => assert_eq!(z, 2.5),
+            _ => panic!("expected DataRate::Gbps variant"),
+        }
+    }
+
+    #[test]
+    fn test_sub() {
+        match DataRate::Mbps(100.0) - DataRate::Kbps(2500.0) {
+            DataRate::Mbps(z) => assert_eq!(z, 97.5),
+            _ => panic!("expected DataRate::Mbps variant"),
+        }
+    }
+
+    #[test]
+    fn test_scalar_ops() {
+        let rate = DataRate::Gbps(10.0);
+        assert_eq!(rate * 4.0, DataRate::Gbps(40.0));
+        assert_eq!(4.0 * rate, DataRate::Gbps(40.0));
+        assert_eq!(rate / 4.0, DataRate::Gbps(2.5));
+    }
+
+    /// One of every variant alongside its unit and its exact size in bits per second.
+    fn unit_table() -> Vec<(DataRate, DataRateUnit, f64)> {
+        vec![
+            (DataRate::bps(1.0), DataRateUnit::bps, 1.0),
+            (DataRate::Kbps(1.0), DataRateUnit::Kbps, Data::BYTES_PER_KB),
+            (DataRate::KBps(1.0), DataRateUnit::KBps, Data::BYTES_PER_KB * DataRate::BITS_PER_BYTE),
+            (DataRate::Kibps(1.0), DataRateUnit::Kibps, Data::BYTES_PER_KBI),
+            (DataRate::KiBps(1.0), DataRateUnit::KiBps, Data::BYTES_PER_KBI * DataRate::BITS_PER_BYTE),
+            (DataRate::Mbps(1.0), DataRateUnit::Mbps, Data::BYTES_PER_MB),
+            (DataRate::MBps(1.0), DataRateUnit::MBps, Data::BYTES_PER_MB * DataRate::BITS_PER_BYTE),
+            (DataRate::Mibps(1.0), DataRateUnit::Mibps, Data::BYTES_PER_MBI),
+            (DataRate::MiBps(1.0), DataRateUnit::MiBps, Data::BYTES_PER_MBI * DataRate::BITS_PER_BYTE),
+            (DataRate::Gbps(1.0), DataRateUnit::Gbps, Data::BYTES_PER_GB),
+            (DataRate::GBps(1.0), DataRateUnit::GBps, Data::BYTES_PER_GB * DataRate::BITS_PER_BYTE),
+            (DataRate::Gibps(1.0), DataRateUnit::Gibps, Data::BYTES_PER_GBI),
+            (DataRate::GiBps(1.0), DataRateUnit::GiBps, Data::BYTES_PER_GBI * DataRate::BITS_PER_BYTE),
+            (DataRate::Tbps(1.0), DataRateUnit::Tbps, Data::BYTES_PER_TB),
+            (DataRate::TBps(1.0), DataRateUnit::TBps, Data::BYTES_PER_TB * DataRate::BITS_PER_BYTE),
+            (DataRate::Tibps(1.0), DataRateUnit::Tibps, Data::BYTES_PER_TBI),
+            (DataRate::TiBps(1.0), DataRateUnit::TiBps, Data::BYTES_PER_TBI * DataRate::BITS_PER_BYTE),
+        ]
+    }
+
+    #[test]
+    fn test_to_base_table() {
+        for (rate, _, bps) in unit_table() {
+            match rate.to_base() {
+                DataRate::bps(x) => assert_eq!(x, bps, "{} to bps", rate),
+                _ => panic!("expected DataRate::bps variant"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_binary_prefixes() {
+        assert_eq!(DataRate::Kibps(1.0).to_base().value(), 1024.0);
+        assert_eq!(DataRate::KiBps(1.0).to_base().value(), 8.0 * 1024.0);
+        assert_eq!(DataRate::Mibps(1.0).to_base().value(), 1024.0 * 1024.0);
+        assert_eq!(DataRate::MiBps(1.0).to_base().value(), 8.0 * 1024.0 * 1024.0);
+        assert_eq!(DataRate::Gibps(1.0).to_base().value(), 1024.0 * 1024.0 * 1024.0);
+        assert_eq!(DataRate::TiBps(1.0).to_base().value(), 8.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0);
+        assert_eq!(DataRate::MiBps(1.0), DataRate::Mibps(8.0));
+        assert_eq!(DataRate::Gibps(1.0), DataRate::Mibps(1024.0));
+    }
+
+    #[test]
+    fn test_round_trip_table() {
+        for (from, from_unit, from_bps) in unit_table() {
+            for (_, to_unit, to_bps) in unit_table() {
+                let converted = from.to(to_unit).value();
+                let expected = from_bps / to_bps;
+                assert!(((converted - expected) / expected).abs() < 1e-12, "{} to {:?}", from, to_unit);
+
+                let back = from.to(to_unit).to(from_unit).value();
+                assert!((back - 1.0).abs() < 1e-12, "{} via {:?}", from, to_unit);
+            }
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", DataRate::Gbps(2.5)), "2.5 Gbps");
+        assert_eq!(format!("{}", DataRate::bps(300.0)), "300 bps");
+        assert_eq!(format!("{}", DataRate::MBps(120.0)), "120 MBps");
+        assert_eq!(format!("{}", DataRate::MiBps(64.0)), "64 MiBps");
+        assert_eq!(format!("{}", DataRate::Tibps(1.0)), "1 Tibps");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("100 Mbps".parse::<DataRate>(), Ok(DataRate::Mbps(100.0)));
+        assert_eq!("12.5 MB/s".parse::<DataRate>(), Ok(DataRate::MBps(12.5)));
+        assert_eq!("1Gib/s".parse::<DataRate>(), Ok(DataRate::Gibps(1.0)));
+        assert_eq!("56 kbps".parse::<DataRate>(), Ok(DataRate::Kbps(56.0)));
+        assert_eq!("8 b/s".parse::<DataRate>(), Ok(DataRate::bps(8.0)));
+        assert_eq!("10 megabits per second".parse::<DataRate>(), Ok(DataRate::Mbps(10.0)));
+        assert_eq!("1 Gibibyte  per Second".parse::<DataRate>(), Ok(DataRate::GiBps(1.0)));
+
+        // Megabits and megabytes differ only by the case of the `b`.
+        match "10 Mb/s".parse::<DataRate>() {
+            Ok(DataRate::Mbps(x)) => assert_eq!(x, 10.0),
+            _ => panic!("expected DataRate::Mbps variant"),
+        }
+        match "10 MB/s".parse::<DataRate>() {
+            Ok(DataRate::MBps(x)) => assert_eq!(x, 10.0),
+            _ => panic!("expected DataRate::MBps variant"),
+        }
+    }
+
+    #[test]
+    fn test_from_str_round_trip() {
+        for (_, unit, _) in unit_table() {
+            let rate = DataRate::bps(3.0e9).to(unit);
+            let parsed: DataRate = rate.to_string().parse().unwrap();
+            assert_eq!(parsed.to_string(), rate.to_string());
+            let parsed: DataRate = format!("{:#}", rate).parse().unwrap();
+            assert_eq!(parsed.to_string(), rate.to_string());
+        }
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        assert_eq!("10 mbps".parse::<DataRate>(), Err(ParseUnitError::UnknownUnit("mbps".to_string())));
+        assert_eq!("10 MB".parse::<DataRate>(), Err(ParseUnitError::UnknownUnit("MB".to_string())));
+        assert_eq!("10 megabits".parse::<DataRate>(), Err(ParseUnitError::UnknownUnit("megabits".to_string())));
+        assert_eq!("fast Mbps".parse::<DataRate>(), Err(ParseUnitError::InvalidNumber("fast".to_string())));
+    }
+
+    #[test]
+    fn test_display_format() {
+        assert_eq!(format!("{:.2}", DataRate::Gbps(2.5)), "2.50 Gbps");
+        assert_eq!(format!("{:#}", DataRate::MiBps(8.0)), "8 mebibytes per second");
+        assert_eq!(format!("{:011.1}", DataRate::Mbps(-1.0)), "-001.0 Mbps");
+    }
+}
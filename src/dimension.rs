@@ -0,0 +1,275 @@
+//! This module encodes physical dimensions at the type level for the generic `Quantity` type.
+//!
+//! A dimension is a `Dim` whose parameters are the exponents of length, mass, time,
+//! thermodynamic temperature, information and plane angle, in that order. Electric current,
+//! amount of substance and luminous intensity are left out since no unit type in this crate uses
+//! them. Angle is not an SI base dimension, but tracking it keeps radians per second apart from
+//! hertz, which would otherwise share the dimension of inverse time.
+//!
+//! The exponents are type-level integers rather than const generics, since adding const generic
+//! parameters together in a type is not yet stable. Zero is `Z0`, and every other integer is
+//! built by wrapping it in `Succ` (plus one) or `Pred` (minus one), e.g. `Succ<Succ<Z0>>` is 2
+//! and `Pred<Z0>` is -1. The `IntAdd`, `IntSub` and `IntNeg` traits do the arithmetic, and
+//! `DimMul` and `DimDiv` apply it to each exponent so multiplying two quantities yields the
+//! dimension of the product.
+//!
+//! The aliases at the bottom of the module name the dimensions of the crate's unit types.
+
+use std::marker::PhantomData;
+
+
+/*--------------------( Type-level integers )--------------------*/
+
+/// The type-level integer zero.
+pub struct Z0;
+
+/// The type-level integer one greater than `N`.
+pub struct Succ<N>(PhantomData<N>);
+
+/// The type-level integer one less than `N`.
+pub struct Pred<N>(PhantomData<N>);
+
+pub type P1 = Succ<Z0>;
+pub type P2 = Succ<P1>;
+pub type P3 = Succ<P2>;
+pub type N1 = Pred<Z0>;
+pub type N2 = Pred<N1>;
+pub type N3 = Pred<N2>;
+
+/// A type-level integer and its value.
+pub trait Integer {
+    const VALUE: i32;
+}
+
+impl Integer for Z0 {
+    const VALUE: i32 = 0;
+}
+
+impl<N: Integer> Integer for Succ<N> {
+    const VALUE: i32 = N::VALUE + 1;
+}
+
+impl<N: Integer> Integer for Pred<N> {
+    const VALUE: i32 = N::VALUE - 1;
+}
+
+/// Adds one to a type-level integer, unwrapping a `Pred` rather than wrapping it in a `Succ`
+/// so every integer keeps a single representation.
+pub trait Increment {
+    type Output;
+}
+
+impl Increment for Z0 {
+    type Output = Succ<Z0>;
+}
+
+impl<N> Increment for Succ<N> {
+    type Output = Succ<Succ<N>>;
+}
+
+impl<N> Increment for Pred<N> {
+    type Output = N;
+}
+
+/// Subtracts one from a type-level integer, the counterpart of `Increment`.
+pub trait Decrement {
+    type Output;
+}
+
+impl Decrement for Z0 {
+    type Output = Pred<Z0>;
+}
+
+impl<N> Decrement for Succ<N> {
+    type Output = N;
+}
+
+impl<N> Decrement for Pred<N> {
+    type Output = Pred<Pred<N>>;
+}
+
+/// Adds two type-level integers.
+pub trait IntAdd<Rhs> {
+    type Output;
+}
+
+impl<Rhs> IntAdd<Rhs> for Z0 {
+    type Output = Rhs;
+}
+
+impl<N, Rhs> IntAdd<Rhs> for Succ<N>
+where
+    N: IntAdd<Rhs>,
+    <N as IntAdd<Rhs>>::Output: Increment,
+{
+    type Output = <<N as IntAdd<Rhs>>::Output as Increment>::Output;
+}
+
+impl<N, Rhs> IntAdd<Rhs> for Pred<N>
+where
+    N: IntAdd<Rhs>,
+    <N as IntAdd<Rhs>>::Output: Decrement,
+{
+    type Output = <<N as IntAdd<Rhs>>::Output as Decrement>::Output;
+}
+
+/// Negates a type-level integer.
+pub trait IntNeg {
+    type Output;
+}
+
+impl IntNeg for Z0 {
+    type Output = Z0;
+}
+
+impl<N: IntNeg> IntNeg for Succ<N> {
+    type Output = Pred<<N as IntNeg>::Output>;
+}
+
+impl<N: IntNeg> IntNeg for Pred<N> {
+    type Output = Succ<<N as IntNeg>::Output>;
+}
+
+/// Subtracts one type-level integer from another by adding its negation.
+pub trait IntSub<Rhs> {
+    type Output;
+}
+
+impl<N, Rhs> IntSub<Rhs> for N
+where
+    Rhs: IntNeg,
+    N: IntAdd<<Rhs as IntNeg>::Output>,
+{
+    type Output = <N as IntAdd<<Rhs as IntNeg>::Output>>::Output;
+}
+
+
+/*--------------------( Dimensions )--------------------*/
+
+/// A dimension with the given exponents of length, mass, time, temperature, information and
+/// angle.
+pub struct Dim<L, M, T, Th, I, A>(PhantomData<(L, M, T, Th, I, A)>);
+
+/// A dimension and its exponents, which are known at compile time.
+pub trait Dimension {
+    /// The exponents of length, mass, time, temperature, information and angle, in that order.
+    const EXPONENTS: [i32; 6];
+}
+
+impl<L, M, T, Th, I, A> Dimension for Dim<L, M, T, Th, I, A>
+where
+    L: Integer, M: Integer, T: Integer, Th: Integer, I: Integer, A: Integer,
+{
+    const EXPONENTS: [i32; 6] = [L::VALUE, M::VALUE, T::VALUE, Th::VALUE, I::VALUE, A::VALUE];
+}
+
+/// The dimension of the product of two quantities.
+pub trait DimMul<Rhs> {
+    type Output;
+}
+
+impl<L1, M1, T1, Th1, I1, A1, L2, M2, T2, Th2, I2, A2> DimMul<Dim<L2, M2, T2, Th2, I2, A2>> for Dim<L1, M1, T1, Th1, I1, A1>
+where
+    L1: IntAdd<L2>, M1: IntAdd<M2>, T1: IntAdd<T2>, Th1: IntAdd<Th2>, I1: IntAdd<I2>, A1: IntAdd<A2>,
+{
+    type Output = Dim<
+        <L1 as IntAdd<L2>>::Output,
+        <M1 as IntAdd<M2>>::Output,
+        <T1 as IntAdd<T2>>::Output,
+        <Th1 as IntAdd<Th2>>::Output,
+        <I1 as IntAdd<I2>>::Output,
+        <A1 as IntAdd<A2>>::Output,
+    >;
+}
+
+/// The dimension of the quotient of two quantities.
+pub trait DimDiv<Rhs> {
+    type Output;
+}
+
+impl<L1, M1, T1, Th1, I1, A1, L2, M2, T2, Th2, I2, A2> DimDiv<Dim<L2, M2, T2, Th2, I2, A2>> for Dim<L1, M1, T1, Th1, I1, A1>
+where
+    L1: IntSub<L2>, M1: IntSub<M2>, T1: IntSub<T2>, Th1: IntSub<Th2>, I1: IntSub<I2>, A1: IntSub<A2>,
+{
+    type Output = Dim<
+        <L1 as IntSub<L2>>::Output,
+        <M1 as IntSub<M2>>::Output,
+        <T1 as IntSub<T2>>::Output,
+        <Th1 as IntSub<Th2>>::Output,
+        <I1 as IntSub<I2>>::Output,
+        <A1 as IntSub<A2>>::Output,
+    >;
+}
+
+
+/*--------------------( Named dimensions )--------------------*/
+
+pub type Dimensionless = Dim<Z0, Z0, Z0, Z0, Z0, Z0>;
+
+pub type Length = Dim<P1, Z0, Z0, Z0, Z0, Z0>;
+pub type Mass = Dim<Z0, P1, Z0, Z0, Z0, Z0>;
+pub type Time = Dim<Z0, Z0, P1, Z0, Z0, Z0>;
+/// The dimension of a temperature difference. Absolute temperatures have no `Quantity` since
+/// two of them cannot be added.
+pub type Temperature = Dim<Z0, Z0, Z0, P1, Z0, Z0>;
+pub type Data = Dim<Z0, Z0, Z0, Z0, P1, Z0>;
+pub type Angle = Dim<Z0, Z0, Z0, Z0, Z0, P1>;
+
+pub type Area = Dim<P2, Z0, Z0, Z0, Z0, Z0>;
+pub type Volume = Dim<P3, Z0, Z0, Z0, Z0, Z0>;
+pub type Speed = Dim<P1, Z0, N1, Z0, Z0, Z0>;
+pub type Acceleration = Dim<P1, Z0, N2, Z0, Z0, Z0>;
+pub type Force = Dim<P1, P1, N2, Z0, Z0, Z0>;
+pub type Pressure = Dim<N1, P1, N2, Z0, Z0, Z0>;
+pub type Energy = Dim<P2, P1, N2, Z0, Z0, Z0>;
+pub type Power = Dim<P2, P1, N3, Z0, Z0, Z0>;
+pub type Frequency = Dim<Z0, Z0, N1, Z0, Z0, Z0>;
+pub type DataRate = Dim<Z0, Z0, N1, Z0, P1, Z0>;
+pub type AngularSpeed = Dim<Z0, Z0, N1, Z0, Z0, P1>;
+pub type AngularAcceleration = Dim<Z0, Z0, N2, Z0, Z0, P1>;
+
+
+/*--------------------( Unit Tests )--------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Only compiles when `A` and `B` are the same type.
+    fn assert_same_type<A, B>()
+    where
+        A: IntAdd<Z0, Output = B>,
+    {}
+
+    #[test]
+    fn test_integer_values() {
+        assert_eq!(Z0::VALUE, 0);
+        assert_eq!(P3::VALUE, 3);
+        assert_eq!(N2::VALUE, -2);
+    }
+
+    #[test]
+    fn test_integer_arithmetic() {
+        assert_eq!(<P2 as IntAdd<P1>>::Output::VALUE, 3);
+        assert_eq!(<P2 as IntAdd<N3>>::Output::VALUE, -1);
+        assert_eq!(<N1 as IntSub<N3>>::Output::VALUE, 2);
+        assert_eq!(<P2 as IntNeg>::Output::VALUE, -2);
+
+        // The results are in the same form as the aliases, not merely equal in value.
+        assert_same_type::<<P2 as IntAdd<N1>>::Output, P1>();
+        assert_same_type::<<N1 as IntAdd<P1>>::Output, Z0>();
+        assert_same_type::<<P1 as IntSub<P3>>::Output, N2>();
+    }
+
+    #[test]
+    fn test_dimension_arithmetic() {
+        assert_eq!(<Length as DimMul<Length>>::Output::EXPONENTS, Area::EXPONENTS);
+        assert_eq!(<Force as DimDiv<Area>>::Output::EXPONENTS, Pressure::EXPONENTS);
+        assert_eq!(<Energy as DimDiv<Time>>::Output::EXPONENTS, Power::EXPONENTS);
+        assert_eq!(<Data as DimDiv<Time>>::Output::EXPONENTS, DataRate::EXPONENTS);
+        assert_eq!(<Angle as DimDiv<Time>>::Output::EXPONENTS, AngularSpeed::EXPONENTS);
+        assert_eq!(<Speed as DimDiv<Speed>>::Output::EXPONENTS, [0, 0, 0, 0, 0, 0]);
+        assert_eq!(Power::EXPONENTS, [2, 1, -3, 0, 0, 0]);
+        assert_ne!(AngularSpeed::EXPONENTS, Frequency::EXPONENTS);
+    }
+}
@@ -0,0 +1,352 @@
+//! This module contains the `Energy` enum and its associated unit conversions.
+//! The `Energy` enum is used to represent energies in joules, kilowatt-hours, calories, etc.
+//! The `EnergyUnit` enum is used to specify the unit of the energy.
+//! The `Energy` enum implements the `Unit` and `UnitConversion` traits, which offer the ability to 
+//! convert between different units of energy via the `to_base` and `to` methods.
+
+use std::ops::{Mul, Div};
+use std::fmt::Display;
+use crate::unit_traits::UnitConversion;
+use crate::unit_traits::Unit;
+use crate::force::Force;
+use crate::length::Length;
+use crate::quantity::Quantity;
+use crate::dimension;
+use crate::utils;
+
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[allow(non_camel_case_types)]
+pub enum EnergyUnit {
+    Joules,
+    J,
+    Kilojoules,
+    kJ,
+    WattHours,
+    Wh,
+    KilowattHours,
+    kWh,
+    Calories,
+    cal,
+    Kilocalories,
+    kcal,
+    BritishThermalUnits,
+    BTU,
+    Electronvolts,
+    eV,
+    Ergs,
+    erg,
+}
+
+
+/// The `Energy` enum represents energies in joules, kilowatt-hours, calories, etc.
+#[derive(Debug, Clone, Copy)]
+pub enum Energy {
+    Joules(f64),
+    Kilojoules(f64),
+    WattHours(f64),
+    KilowattHours(f64),
+    Calories(f64),
+    Kilocalories(f64),
+    BritishThermalUnits(f64),
+    Electronvolts(f64),
+    Ergs(f64),
+}
+
+impl Energy {
+    pub const JOULES_PER_KILOJOULE: f64 = 1000.0;
+    pub const JOULES_PER_WATT_HOUR: f64 = 3600.0;
+    pub const JOULES_PER_KILOWATT_HOUR: f64 = 3.6e6;
+    pub const JOULES_PER_CALORIE: f64 = 4.184;
+    pub const JOULES_PER_KILOCALORIE: f64 = 4184.0;
+    pub const JOULES_PER_BTU: f64 = 1_055.055_852_62;
+    pub const JOULES_PER_ELECTRONVOLT: f64 = 1.602_176_634e-19;
+    pub const JOULES_PER_ERG: f64 = 1.0e-7;
+
+    /* ALIASES */
+
+    #[allow(non_snake_case)]
+    pub fn J(x: f64) -> Energy {
+        Energy::Joules(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn kJ(x: f64) -> Energy {
+        Energy::Kilojoules(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn Wh(x: f64) -> Energy {
+        Energy::WattHours(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn kWh(x: f64) -> Energy {
+        Energy::KilowattHours(x)
+    }
+
+    pub fn cal(x: f64) -> Energy {
+        Energy::Calories(x)
+    }
+
+    pub fn kcal(x: f64) -> Energy {
+        Energy::Kilocalories(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn BTU(x: f64) -> Energy {
+        Energy::BritishThermalUnits(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn eV(x: f64) -> Energy {
+        Energy::Electronvolts(x)
+    }
+
+    pub fn erg(x: f64) -> Energy {
+        Energy::Ergs(x)
+    }
+}
+
+impl Unit for Energy {
+    /// Returns the inner f64 value of the Energy enum.
+    fn value(&self) -> f64 {
+        match self {
+            Energy::Joules(x) => *x,
+            Energy::Kilojoules(x) => *x,
+            Energy::WattHours(x) => *x,
+            Energy::KilowattHours(x) => *x,
+            Energy::Calories(x) => *x,
+            Energy::Kilocalories(x) => *x,
+            Energy::BritishThermalUnits(x) => *x,
+            Energy::Electronvolts(x) => *x,
+            Energy::Ergs(x) => *x,
+        }
+    }
+}
+
+impl UnitConversion for Energy {
+    type Units = EnergyUnit;
+
+    /// Returns the unit the Energy is expressed in.
+    fn unit(&self) -> EnergyUnit {
+        match self {
+            Energy::Joules(_) => EnergyUnit::Joules,
+            Energy::Kilojoules(_) => EnergyUnit::Kilojoules,
+            Energy::WattHours(_) => EnergyUnit::WattHours,
+            Energy::KilowattHours(_) => EnergyUnit::KilowattHours,
+            Energy::Calories(_) => EnergyUnit::Calories,
+            Energy::Kilocalories(_) => EnergyUnit::Kilocalories,
+            Energy::BritishThermalUnits(_) => EnergyUnit::BritishThermalUnits,
+            Energy::Electronvolts(_) => EnergyUnit::Electronvolts,
+            Energy::Ergs(_) => EnergyUnit::Ergs,
+        }
+    }
+
+    /// Converts a given instance of a Energy enum into the base variant, `Energy::Joules`.
+    fn to_base(&self) -> Energy {
+        match self {
+            Energy::Joules(x) => Energy::Joules(*x),
+            Energy::Kilojoules(x) => Energy::Joules(x * Energy::JOULES_PER_KILOJOULE),
+            Energy::WattHours(x) => Energy::Joules(x * Energy::JOULES_PER_WATT_HOUR),
+            Energy::KilowattHours(x) => Energy::Joules(x * Energy::JOULES_PER_KILOWATT_HOUR),
+            Energy::Calories(x) => Energy::Joules(x * Energy::JOULES_PER_CALORIE),
+            Energy::Kilocalories(x) => Energy::Joules(x * Energy::JOULES_PER_KILOCALORIE),
+            Energy::BritishThermalUnits(x) => Energy::Joules(x * Energy::JOULES_PER_BTU),
+            Energy::Electronvolts(x) => Energy::Joules(x * Energy::JOULES_PER_ELECTRONVOLT),
+            Energy::Ergs(x) => Energy::Joules(x * Energy::JOULES_PER_ERG),
+        }
+    }
+
+    /// Converts a given instance of a Energy enum into the specified variant as indicated by
+    /// the `EnergyUnit` provided.
+    fn to(&self, variant: EnergyUnit) -> Energy {
+        let base = self.to_base().value();
+        match variant {
+            EnergyUnit::Joules | EnergyUnit::J => Energy::Joules(base),
+            EnergyUnit::Kilojoules | EnergyUnit::kJ => Energy::Kilojoules(base / Energy::JOULES_PER_KILOJOULE),
+            EnergyUnit::WattHours | EnergyUnit::Wh => Energy::WattHours(base / Energy::JOULES_PER_WATT_HOUR),
+            EnergyUnit::KilowattHours | EnergyUnit::kWh => Energy::KilowattHours(base / Energy::JOULES_PER_KILOWATT_HOUR),
+            EnergyUnit::Calories | EnergyUnit::cal => Energy::Calories(base / Energy::JOULES_PER_CALORIE),
+            EnergyUnit::Kilocalories | EnergyUnit::kcal => Energy::Kilocalories(base / Energy::JOULES_PER_KILOCALORIE),
+            EnergyUnit::BritishThermalUnits | EnergyUnit::BTU => Energy::BritishThermalUnits(base / Energy::JOULES_PER_BTU),
+            EnergyUnit::Electronvolts | EnergyUnit::eV => Energy::Electronvolts(base / Energy::JOULES_PER_ELECTRONVOLT),
+            EnergyUnit::Ergs | EnergyUnit::erg => Energy::Ergs(base / Energy::JOULES_PER_ERG),
+        }
+    }
+}
+
+
+impl Mul<f64> for Energy {
+    type Output = Energy;
+
+    fn mul(self, other: f64) -> Energy {
+        match self {
+            Energy::Joules(x) => Energy::Joules(x * other),
+            Energy::Kilojoules(x) => Energy::Kilojoules(x * other),
+            Energy::WattHours(x) => Energy::WattHours(x * other),
+            Energy::KilowattHours(x) => Energy::KilowattHours(x * other),
+            Energy::Calories(x) => Energy::Calories(x * other),
+            Energy::Kilocalories(x) => Energy::Kilocalories(x * other),
+            Energy::BritishThermalUnits(x) => Energy::BritishThermalUnits(x * other),
+            Energy::Electronvolts(x) => Energy::Electronvolts(x * other),
+            Energy::Ergs(x) => Energy::Ergs(x * other),
+        }
+    }
+}
+
+
+impl Mul<Energy> for f64 {
+    type Output = Energy;
+
+    fn mul(self, other: Energy) -> Energy {
+        match other {
+            Energy::Joules(x) => Energy::Joules(x * self),
+            Energy::Kilojoules(x) => Energy::Kilojoules(x * self),
+            Energy::WattHours(x) => Energy::WattHours(x * self),
+            Energy::KilowattHours(x) => Energy::KilowattHours(x * self),
+            Energy::Calories(x) => Energy::Calories(x * self),
+            Energy::Kilocalories(x) => Energy::Kilocalories(x * self),
+            Energy::BritishThermalUnits(x) => Energy::BritishThermalUnits(x * self),
+            Energy::Electronvolts(x) => Energy::Electronvolts(x * self),
+            Energy::Ergs(x) => Energy::Ergs(x * self),
+        }
+    }
+}
+
+
+impl Div<f64> for Energy {
+    type Output = Energy;
+
+    fn div(self, other: f64) -> Energy {
+        match self {
+            Energy::Joules(x) => Energy::Joules(x / other),
+            Energy::Kilojoules(x) => Energy::Kilojoules(x / other),
+            Energy::WattHours(x) => Energy::WattHours(x / other),
+            Energy::KilowattHours(x) => Energy::KilowattHours(x / other),
+            Energy::Calories(x) => Energy::Calories(x / other),
+            Energy::Kilocalories(x) => Energy::Kilocalories(x / other),
+            Energy::BritishThermalUnits(x) => Energy::BritishThermalUnits(x / other),
+            Energy::Electronvolts(x) => Energy::Electronvolts(x / other),
+            Energy::Ergs(x) => Energy::Ergs(x / other),
+        }
+    }
+}
+
+
+impl Display for Energy {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (x, symbol, name) = match self {
+            Energy::Joules(x) => (x, "J", "joules"),
+            Energy::Kilojoules(x) => (x, "kJ", "kilojoules"),
+            Energy::WattHours(x) => (x, "Wh", "watt-hours"),
+            Energy::KilowattHours(x) => (x, "kWh", "kilowatt-hours"),
+            Energy::Calories(x) => (x, "cal", "calories"),
+            Energy::Kilocalories(x) => (x, "kcal", "kilocalories"),
+            Energy::BritishThermalUnits(x) => (x, "BTU", "British thermal units"),
+            Energy::Electronvolts(x) => (x, "eV", "electronvolts"),
+            Energy::Ergs(x) => (x, "erg", "ergs"),
+        };
+        utils::fmt_quantity(f, *x, symbol, name)
+    }
+}
+
+
+impl Mul<Length> for Force {
+    type Output = Energy;
+
+    /// Multiplies a force by a length. Dynes produce ergs; everything else produces joules.
+    fn mul(self, rhs: Length) -> Energy {
+        let energy: Quantity<dimension::Energy> = Quantity::from(self) * Quantity::from(rhs);
+        match self {
+            Force::Dynes(_) => Energy::from(energy).to(EnergyUnit::Ergs),
+            _ => Energy::from(energy),
+        }
+    }
+}
+
+
+/*--------------------( Unit Tests )--------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_base() {
+        match Energy::KilowattHours(2.0).to_base() {
+            Energy::Joules(x) => assert_eq!(x, 7.2e6),
+            _ => panic!("expected Energy::Joules variant"),
+        }
+        assert_eq!(Energy::Kilocalories(1.0).to_base().value(), 4184.0);
+    }
+
+    #[test]
+    fn test_from_base() {
+        match Energy::Joules(3600.0).to(EnergyUnit::Wh) {
+            Energy::WattHours(x) => assert_eq!(x, 1.0),
+            _ => panic!("expected Energy::WattHours variant"),
+        }
+        let kwh = Energy::KilowattHours(1.0);
+        assert!((kwh.to(EnergyUnit::BTU).value() - 3_412.141_633).abs() < 1e-6);
+        let ev = Energy::Electronvolts(1.0);
+        assert!((ev.to(EnergyUnit::erg).value() - 1.602_176_634e-12).abs() < 1e-24);
+    }
+
+    #[test]
+    fn test_cmp() {
+        assert!(Energy::Kilocalories(1.0) > Energy::BritishThermalUnits(3.0));
+        assert!(Energy::WattHours(1.0) > Energy::Kilojoules(3.5));
+        assert_eq!(Energy::Kilojoules(3.6), Energy::WattHours(1.0));
+
+        // Equality is relative, so it still tells apart energies at atomic scales.
+        assert_ne!(Energy::Electronvolts(1.0), Energy::Electronvolts(1000.0));
+        assert_ne!(Energy::Electronvolts(13.6), Energy::Electronvolts(13.7));
+        assert_eq!(Energy::Electronvolts(1000.0), Energy::Electronvolts(1000.0));
+        assert!(Energy::Electronvolts(1.0) < Energy::Electronvolts(1000.0));
+        assert!(Energy::Ergs(1.0) > Energy::Electronvolts(1.0));
+    }
+
+    #[test]
+    fn test_add_sub() {
+        match Energy::KilowattHours(1.0) + Energy::WattHours(500.0) {
+            Energy::KilowattHours(x) => assert_eq!(x, 1.5),
+            _ => panic!("expected Energy::KilowattHours variant"),
+        }
+        match Energy::Kilojoules(10.0) - Energy::Joules(2500.0) {
+            Energy::Kilojoules(x) => assert_eq!(x, 7.5),
+            _ => panic!("expected Energy::Kilojoules variant"),
+        }
+    }
+
+    #[test]
+    fn test_scalar_ops() {
+        let energy = Energy::Kilocalories(250.0);
+        assert_eq!(energy * 2.0, Energy::Kilocalories(500.0));
+        assert_eq!(2.0 * energy, Energy::Kilocalories(500.0));
+        assert_eq!(energy / 5.0, Energy::Kilocalories(50.0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Energy::Joules(12.5)), "12.5 J");
+        assert_eq!(format!("{}", Energy::KilowattHours(3.0)), "3 kWh");
+        assert_eq!(format!("{}", Energy::Electronvolts(13.6)), "13.6 eV");
+    }
+
+    #[test]
+    fn test_force_times_length() {
+        match Force::Newtons(10.0) * Length::Meters(3.0) {
+            Energy::Joules(x) => assert_eq!(x, 30.0),
+            _ => panic!("expected Energy::Joules variant"),
+        }
+        match Force::Kilonewtons(2.0) * Length::Kilometers(1.5) {
+            Energy::Joules(x) => assert_eq!(x, 3.0e6),
+            _ => panic!("expected Energy::Joules variant"),
+        }
+        match Force::Dynes(1.0) * Length::Meters(0.01) {
+            Energy::Ergs(x) => assert!((x - 1.0).abs() < 1e-12),
+            _ => panic!("expected Energy::Ergs variant"),
+        }
+    }
+}
@@ -0,0 +1,282 @@
+//! This module contains the `Force` enum and its associated unit conversions.
+//! The `Force` enum is used to represent forces in newtons, pounds-force, dynes, etc.
+//! The `ForceUnit` enum is used to specify the unit of the force.
+//! The `Force` enum implements the `Unit` and `UnitConversion` traits, which offer the ability to 
+//! convert between different units of force via the `to_base` and `to` methods.
+
+use std::ops::{Mul, Div};
+use std::fmt::Display;
+use crate::unit_traits::UnitConversion;
+use crate::unit_traits::Unit;
+use crate::mass::Mass;
+use crate::acceleration::Acceleration;
+use crate::quantity::Quantity;
+use crate::dimension;
+use crate::utils;
+
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[allow(non_camel_case_types)]
+pub enum ForceUnit {
+    Newtons,
+    N,
+    Kilonewtons,
+    kN,
+    PoundsForce,
+    lbf,
+    Dynes,
+    dyne,
+    KilogramsForce,
+    kgf,
+}
+
+
+/// The `Force` enum represents forces in newtons, pounds-force, dynes, etc.
+#[derive(Debug, Clone, Copy)]
+pub enum Force {
+    Newtons(f64),
+    Kilonewtons(f64),
+    PoundsForce(f64),
+    Dynes(f64),
+    KilogramsForce(f64),
+}
+
+impl Force {
+    pub const NEWTONS_PER_KILONEWTON: f64 = 1000.0;
+    pub const NEWTONS_PER_POUND_FORCE: f64 = 4.448_221_615_260_5;
+    pub const NEWTONS_PER_DYNE: f64 = 1.0e-5;
+    pub const NEWTONS_PER_KILOGRAM_FORCE: f64 = 9.806_65;
+
+    /* ALIASES */
+
+    #[allow(non_snake_case)]
+    pub fn N(x: f64) -> Force {
+        Force::Newtons(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn kN(x: f64) -> Force {
+        Force::Kilonewtons(x)
+    }
+
+    pub fn lbf(x: f64) -> Force {
+        Force::PoundsForce(x)
+    }
+
+    pub fn dyne(x: f64) -> Force {
+        Force::Dynes(x)
+    }
+
+    pub fn kgf(x: f64) -> Force {
+        Force::KilogramsForce(x)
+    }
+}
+
+impl Unit for Force {
+    /// Returns the inner f64 value of the Force enum.
+    fn value(&self) -> f64 {
+        match self {
+            Force::Newtons(x) => *x,
+            Force::Kilonewtons(x) => *x,
+            Force::PoundsForce(x) => *x,
+            Force::Dynes(x) => *x,
+            Force::KilogramsForce(x) => *x,
+        }
+    }
+}
+
+impl UnitConversion for Force {
+    type Units = ForceUnit;
+
+    /// Returns the unit the Force is expressed in.
+    fn unit(&self) -> ForceUnit {
+        match self {
+            Force::Newtons(_) => ForceUnit::Newtons,
+            Force::Kilonewtons(_) => ForceUnit::Kilonewtons,
+            Force::PoundsForce(_) => ForceUnit::PoundsForce,
+            Force::Dynes(_) => ForceUnit::Dynes,
+            Force::KilogramsForce(_) => ForceUnit::KilogramsForce,
+        }
+    }
+
+    /// Converts a given instance of a Force enum into the base variant, `Force::Newtons`.
+    fn to_base(&self) -> Force {
+        match self {
+            Force::Newtons(x) => Force::Newtons(*x),
+            Force::Kilonewtons(x) => Force::Newtons(x * Force::NEWTONS_PER_KILONEWTON),
+            Force::PoundsForce(x) => Force::Newtons(x * Force::NEWTONS_PER_POUND_FORCE),
+            Force::Dynes(x) => Force::Newtons(x * Force::NEWTONS_PER_DYNE),
+            Force::KilogramsForce(x) => Force::Newtons(x * Force::NEWTONS_PER_KILOGRAM_FORCE),
+        }
+    }
+
+    /// Converts a given instance of a Force enum into the specified variant as indicated by
+    /// the `ForceUnit` provided.
+    fn to(&self, variant: ForceUnit) -> Force {
+        let base = self.to_base().value();
+        match variant {
+            ForceUnit::Newtons | ForceUnit::N => Force::Newtons(base),
+            ForceUnit::Kilonewtons | ForceUnit::kN => Force::Kilonewtons(base / Force::NEWTONS_PER_KILONEWTON),
+            ForceUnit::PoundsForce | ForceUnit::lbf => Force::PoundsForce(base / Force::NEWTONS_PER_POUND_FORCE),
+            ForceUnit::Dynes | ForceUnit::dyne => Force::Dynes(base / Force::NEWTONS_PER_DYNE),
+            ForceUnit::KilogramsForce | ForceUnit::kgf => Force::KilogramsForce(base / Force::NEWTONS_PER_KILOGRAM_FORCE),
+        }
+    }
+}
+
+
+impl Mul<f64> for Force {
+    type Output = Force;
+
+    fn mul(self, other: f64) -> Force {
+        match self {
+            Force::Newtons(x) => Force::Newtons(x * other),
+            Force::Kilonewtons(x) => Force::Kilonewtons(x * other),
+            Force::PoundsForce(x) => Force::PoundsForce(x * other),
+            Force::Dynes(x) => Force::Dynes(x * other),
+            Force::KilogramsForce(x) => Force::KilogramsForce(x * other),
+        }
+    }
+}
+
+
+impl Mul<Force> for f64 {
+    type Output = Force;
+
+    fn mul(self, other: Force) -> Force {
+        match other {
+            Force::Newtons(x) => Force::Newtons(x * self),
+            Force::Kilonewtons(x) => Force::Kilonewtons(x * self),
+            Force::PoundsForce(x) => Force::PoundsForce(x * self),
+            Force::Dynes(x) => Force::Dynes(x * self),
+            Force::KilogramsForce(x) => Force::KilogramsForce(x * self),
+        }
+    }
+}
+
+
+impl Div<f64> for Force {
+    type Output = Force;
+
+    fn div(self, other: f64) -> Force {
+        match self {
+            Force::Newtons(x) => Force::Newtons(x / other),
+            Force::Kilonewtons(x) => Force::Kilonewtons(x / other),
+            Force::PoundsForce(x) => Force::PoundsForce(x / other),
+            Force::Dynes(x) => Force::Dynes(x / other),
+            Force::KilogramsForce(x) => Force::KilogramsForce(x / other),
+        }
+    }
+}
+
+
+impl Display for Force {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (x, symbol, name) = match self {
+            Force::Newtons(x) => (x, "N", "newtons"),
+            Force::Kilonewtons(x) => (x, "kN", "kilonewtons"),
+            Force::PoundsForce(x) => (x, "lbf", "pounds-force"),
+            Force::Dynes(x) => (x, "dyn", "dynes"),
+            Force::KilogramsForce(x) => (x, "kgf", "kilograms-force"),
+        };
+        utils::fmt_quantity(f, *x, symbol, name)
+    }
+}
+
+
+impl Mul<Acceleration> for Mass {
+    type Output = Force;
+
+    /// Multiplies a mass by an acceleration. Pounds and kilograms under standard gravity produce
+    /// pounds-force and kilograms-force respectively; everything else produces newtons.
+    fn mul(self, rhs: Acceleration) -> Force {
+        let force: Quantity<dimension::Force> = Quantity::from(self) * Quantity::from(rhs);
+        match (self, rhs) {
+            (Mass::Pounds(_), Acceleration::StandardGravity(_)) => Force::from(force).to(ForceUnit::PoundsForce),
+            (Mass::Kilograms(_), Acceleration::StandardGravity(_)) => Force::from(force).to(ForceUnit::KilogramsForce),
+            _ => Force::from(force),
+        }
+    }
+}
+
+
+/*--------------------( Unit Tests )--------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::assert_close;
+
+    #[test]
+    fn test_to_base() {
+        match Force::Kilonewtons(1.5).to_base() {
+            Force::Newtons(x) => assert_eq!(x, 1500.0),
+            _ => panic!("expected Force::Newtons variant"),
+        }
+        assert_eq!(Force::KilogramsForce(1.0).to_base().value(), 9.806_65);
+    }
+
+    #[test]
+    fn test_from_base() {
+        match Force::Newtons(1.0).to(ForceUnit::dyne) {
+            Force::Dynes(x) => assert!((x - 100_000.0).abs() < 1e-9),
+            _ => panic!("expected Force::Dynes variant"),
+        }
+        let lbf = Force::PoundsForce(1.0);
+        assert!((lbf.to(ForceUnit::kgf).value() - 0.453_592_37).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cmp() {
+        assert!(Force::KilogramsForce(1.0) > Force::PoundsForce(2.0));
+        assert!(Force::Kilonewtons(1.0) > Force::Newtons(999.0));
+        assert_eq!(Force::Kilonewtons(1.0), Force::Newtons(1000.0));
+        assert_ne!(Force::Newtons(1.0e-17), Force::Newtons(2.0e-17));
+        assert!(Force::Dynes(1.0e-12) < Force::Dynes(2.0e-12));
+    }
+
+    #[test]
+    fn test_add_sub() {
+        match Force::Kilonewtons(1.0) + Force::Newtons(500.0) {
+            Force::Kilonewtons(x) => assert_eq!(x, 1.5),
+            _ => panic!("expected Force::Kilonewtons variant"),
+        }
+        match Force::Newtons(1.0) - Force::Dynes(50_000.0) {
+            Force::Newtons(x) => assert_eq!(x, 0.5),
+            _ => panic!("expected Force::Newtons variant"),
+        }
+    }
+
+    #[test]
+    fn test_scalar_ops() {
+        let force = Force::PoundsForce(10.0);
+        assert_eq!(force * 2.0, Force::PoundsForce(20.0));
+        assert_eq!(2.0 * force, Force::PoundsForce(20.0));
+        assert_eq!(force / 5.0, Force::PoundsForce(2.0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Force::Newtons(9.8)), "9.8 N");
+        assert_eq!(format!("{}", Force::PoundsForce(1.0)), "1 lbf");
+        assert_eq!(format!("{}", Force::Dynes(5.0)), "5 dyn");
+    }
+
+    #[test]
+    fn test_mass_times_acceleration() {
+        match Mass::Kilograms(2.0) * Acceleration::MetersPerSecondSquared(3.0) {
+            Force::Newtons(x) => assert_eq!(x, 6.0),
+            _ => panic!("expected Force::Newtons variant"),
+        }
+        match Mass::Pounds(10.0) * Acceleration::StandardGravity(1.0) {
+            Force::PoundsForce(x) => assert_close(x, 10.0),
+            _ => panic!("expected Force::PoundsForce variant"),
+        }
+        match Mass::Kilograms(5.0) * Acceleration::StandardGravity(2.0) {
+            Force::KilogramsForce(x) => assert_close(x, 10.0),
+            _ => panic!("expected Force::KilogramsForce variant"),
+        }
+        let weight = Mass::Grams(500.0) * Acceleration::StandardGravity(1.0);
+        assert_eq!(weight, Force::Newtons(4.903_325));
+    }
+}
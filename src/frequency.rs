@@ -0,0 +1,365 @@
+//! This module contains the `Frequency` enum and its associated unit conversions.
+//! The `Frequency` enum is used to represent frequencies in hertz, revolutions per minute, events per hour, etc.
+//! The `FrequencyUnit` enum is used to specify the unit of the frequency.
+//! The `Frequency` enum implements the `Unit` and `UnitConversion` traits, which offer the ability to 
+//! convert between different units of frequency via the `to_base` and `to` methods.
+
+use std::ops::{Mul, Div};
+use std::fmt::Display;
+use crate::unit_traits::UnitConversion;
+use crate::unit_traits::Unit;
+use crate::time::Time;
+use crate::quantity::Quantity;
+use crate::dimension;
+use crate::utils;
+
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[allow(non_camel_case_types)]
+pub enum FrequencyUnit {
+    Hertz,
+    Hz,
+    Kilohertz,
+    kHz,
+    Megahertz,
+    MHz,
+    Gigahertz,
+    GHz,
+    RevolutionsPerMinute,
+    rpm,
+    PerMinute,
+    per_min,
+    PerHour,
+    per_h,
+}
+
+
+/// The `Frequency` enum represents frequencies in hertz, revolutions per minute, events per hour, etc.
+#[derive(Debug, Clone, Copy)]
+pub enum Frequency {
+    Hertz(f64),
+    Kilohertz(f64),
+    Megahertz(f64),
+    Gigahertz(f64),
+    RevolutionsPerMinute(f64),
+    PerMinute(f64),
+    PerHour(f64),
+}
+
+impl Frequency {
+    pub const HERTZ_PER_KILOHERTZ: f64 = 1.0e3;
+    pub const HERTZ_PER_MEGAHERTZ: f64 = 1.0e6;
+    pub const HERTZ_PER_GIGAHERTZ: f64 = 1.0e9;
+    pub const HERTZ_PER_RPM: f64 = 1.0 / 60.0;
+    pub const HERTZ_PER_PER_MINUTE: f64 = 1.0 / 60.0;
+    pub const HERTZ_PER_PER_HOUR: f64 = 1.0 / 3600.0;
+
+    /* ALIASES */
+
+    #[allow(non_snake_case)]
+    pub fn Hz(x: f64) -> Frequency {
+        Frequency::Hertz(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn kHz(x: f64) -> Frequency {
+        Frequency::Kilohertz(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn MHz(x: f64) -> Frequency {
+        Frequency::Megahertz(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn GHz(x: f64) -> Frequency {
+        Frequency::Gigahertz(x)
+    }
+
+    pub fn rpm(x: f64) -> Frequency {
+        Frequency::RevolutionsPerMinute(x)
+    }
+
+    pub fn per_min(x: f64) -> Frequency {
+        Frequency::PerMinute(x)
+    }
+
+    pub fn per_h(x: f64) -> Frequency {
+        Frequency::PerHour(x)
+    }
+
+    /// Returns the duration of a single cycle. Per-minute and per-hour frequencies produce
+    /// minutes and hours respectively; everything else produces seconds.
+    pub fn period(&self) -> Time {
+        match self {
+            Frequency::RevolutionsPerMinute(x) | Frequency::PerMinute(x) => Time::Minutes(1.0 / x),
+            Frequency::PerHour(x) => Time::Hours(1.0 / x),
+            _ => Time::Seconds(1.0 / self.to_base().value()),
+        }
+    }
+}
+
+impl Unit for Frequency {
+    /// Returns the inner f64 value of the Frequency enum.
+    fn value(&self) -> f64 {
+        match self {
+            Frequency::Hertz(x) => *x,
+            Frequency::Kilohertz(x) => *x,
+            Frequency::Megahertz(x) => *x,
+            Frequency::Gigahertz(x) => *x,
+            Frequency::RevolutionsPerMinute(x) => *x,
+            Frequency::PerMinute(x) => *x,
+            Frequency::PerHour(x) => *x,
+        }
+    }
+}
+
+impl UnitConversion for Frequency {
+    type Units = FrequencyUnit;
+
+    /// Returns the unit the Frequency is expressed in.
+    fn unit(&self) -> FrequencyUnit {
+        match self {
+            Frequency::Hertz(_) => FrequencyUnit::Hertz,
+            Frequency::Kilohertz(_) => FrequencyUnit::Kilohertz,
+            Frequency::Megahertz(_) => FrequencyUnit::Megahertz,
+            Frequency::Gigahertz(_) => FrequencyUnit::Gigahertz,
+            Frequency::RevolutionsPerMinute(_) => FrequencyUnit::RevolutionsPerMinute,
+            Frequency::PerMinute(_) => FrequencyUnit::PerMinute,
+            Frequency::PerHour(_) => FrequencyUnit::PerHour,
+        }
+    }
+
+    /// Converts a given instance of a Frequency enum into the base variant, `Frequency::Hertz`.
+    fn to_base(&self) -> Frequency {
+        match self {
+            Frequency::Hertz(x) => Frequency::Hertz(*x),
+            Frequency::Kilohertz(x) => Frequency::Hertz(x * Frequency::HERTZ_PER_KILOHERTZ),
+            Frequency::Megahertz(x) => Frequency::Hertz(x * Frequency::HERTZ_PER_MEGAHERTZ),
+            Frequency::Gigahertz(x) => Frequency::Hertz(x * Frequency::HERTZ_PER_GIGAHERTZ),
+            Frequency::RevolutionsPerMinute(x) => Frequency::Hertz(x * Frequency::HERTZ_PER_RPM),
+            Frequency::PerMinute(x) => Frequency::Hertz(x * Frequency::HERTZ_PER_PER_MINUTE),
+            Frequency::PerHour(x) => Frequency::Hertz(x * Frequency::HERTZ_PER_PER_HOUR),
+        }
+    }
+
+    /// Converts a given instance of a Frequency enum into the specified variant as indicated by
+    /// the `FrequencyUnit` provided.
+    fn to(&self, variant: FrequencyUnit) -> Frequency {
+        let base = self.to_base().value();
+        match variant {
+            FrequencyUnit::Hertz | FrequencyUnit::Hz => Frequency::Hertz(base),
+            FrequencyUnit::Kilohertz | FrequencyUnit::kHz => Frequency::Kilohertz(base / Frequency::HERTZ_PER_KILOHERTZ),
+            FrequencyUnit::Megahertz | FrequencyUnit::MHz => Frequency::Megahertz(base / Frequency::HERTZ_PER_MEGAHERTZ),
+            FrequencyUnit::Gigahertz | FrequencyUnit::GHz => Frequency::Gigahertz(base / Frequency::HERTZ_PER_GIGAHERTZ),
+            FrequencyUnit::RevolutionsPerMinute | FrequencyUnit::rpm => Frequency::RevolutionsPerMinute(base / Frequency::HERTZ_PER_RPM),
+            FrequencyUnit::PerMinute | FrequencyUnit::per_min => Frequency::PerMinute(base / Frequency::HERTZ_PER_PER_MINUTE),
+            FrequencyUnit::PerHour | FrequencyUnit::per_h => Frequency::PerHour(base / Frequency::HERTZ_PER_PER_HOUR),
+        }
+    }
+}
+
+
+impl Mul<f64> for Frequency {
+    type Output = Frequency;
+
+    fn mul(self, other: f64) -> Frequency {
+        match self {
+            Frequency::Hertz(x) => Frequency::Hertz(x * other),
+            Frequency::Kilohertz(x) => Frequency::Kilohertz(x * other),
+            Frequency::Megahertz(x) => Frequency::Megahertz(x * other),
+            Frequency::Gigahertz(x) => Frequency::Gigahertz(x * other),
+            Frequency::RevolutionsPerMinute(x) => Frequency::RevolutionsPerMinute(x * other),
+            Frequency::PerMinute(x) => Frequency::PerMinute(x * other),
+            Frequency::PerHour(x) => Frequency::PerHour(x * other),
+        }
+    }
+}
+
+
+impl Mul<Frequency> for f64 {
+    type Output = Frequency;
+
+    fn mul(self, other: Frequency) -> Frequency {
+        match other {
+            Frequency::Hertz(x) => Frequency::Hertz(x * self),
+            Frequency::Kilohertz(x) => Frequency::Kilohertz(x * self),
+            Frequency::Megahertz(x) => Frequency::Megahertz(x * self),
+            Frequency::Gigahertz(x) => Frequency::Gigahertz(x * self),
+            Frequency::RevolutionsPerMinute(x) => Frequency::RevolutionsPerMinute(x * self),
+            Frequency::PerMinute(x) => Frequency::PerMinute(x * self),
+            Frequency::PerHour(x) => Frequency::PerHour(x * self),
+        }
+    }
+}
+
+
+impl Div<f64> for Frequency {
+    type Output = Frequency;
+
+    fn div(self, other: f64) -> Frequency {
+        match self {
+            Frequency::Hertz(x) => Frequency::Hertz(x / other),
+            Frequency::Kilohertz(x) => Frequency::Kilohertz(x / other),
+            Frequency::Megahertz(x) => Frequency::Megahertz(x / other),
+            Frequency::Gigahertz(x) => Frequency::Gigahertz(x / other),
+            Frequency::RevolutionsPerMinute(x) => Frequency::RevolutionsPerMinute(x / other),
+            Frequency::PerMinute(x) => Frequency::PerMinute(x / other),
+            Frequency::PerHour(x) => Frequency::PerHour(x / other),
+        }
+    }
+}
+
+
+impl Display for Frequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (x, symbol, name) = match self {
+            Frequency::Hertz(x) => (x, "Hz", "hertz"),
+            Frequency::Kilohertz(x) => (x, "kHz", "kilohertz"),
+            Frequency::Megahertz(x) => (x, "MHz", "megahertz"),
+            Frequency::Gigahertz(x) => (x, "GHz", "gigahertz"),
+            Frequency::RevolutionsPerMinute(x) => (x, "rpm", "revolutions per minute"),
+            Frequency::PerMinute(x) => (x, "/min", "per minute"),
+            Frequency::PerHour(x) => (x, "/h", "per hour"),
+        };
+        utils::fmt_quantity(f, *x, symbol, name)
+    }
+}
+
+
+impl Div<Time> for f64 {
+    type Output = Frequency;
+
+    /// Divides a cycle count by a time. Minutes and hours produce per-minute and per-hour
+    /// frequencies; everything else produces hertz.
+    fn div(self, rhs: Time) -> Frequency {
+        let frequency: Quantity<dimension::Frequency> = self / Quantity::from(rhs);
+        match rhs {
+            Time::Minutes(_) => Frequency::from(frequency).to(FrequencyUnit::PerMinute),
+            Time::Hours(_) => Frequency::from(frequency).to(FrequencyUnit::PerHour),
+            _ => Frequency::from(frequency),
+        }
+    }
+}
+
+
+impl Mul<Time> for Frequency {
+    type Output = f64;
+
+    /// Multiplies a frequency by a time, producing the number of cycles that elapse.
+    fn mul(self, rhs: Time) -> f64 {
+        let cycles: Quantity<dimension::Dimensionless> = Quantity::from(self) * Quantity::from(rhs);
+        cycles.value()
+    }
+}
+
+
+impl Mul<Frequency> for Time {
+    type Output = f64;
+
+    /// Multiplies a time by a frequency, producing the number of cycles that elapse.
+    fn mul(self, rhs: Frequency) -> f64 {
+        rhs * self
+    }
+}
+
+
+/*--------------------( Unit Tests )--------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::TimeUnit;
+
+    #[test]
+    fn test_to_base() {
+        match Frequency::Kilohertz(44.1).to_base() {
+            Frequency::Hertz(x) => assert!((x - 44_100.0).abs() < 1e-9),
+            _ => panic!("expected Frequency::Hertz variant"),
+        }
+        assert_eq!(Frequency::RevolutionsPerMinute(120.0).to_base().value(), 2.0);
+    }
+
+    #[test]
+    fn test_from_base() {
+        match Frequency::Hertz(2.4e9).to(FrequencyUnit::GHz) {
+            Frequency::Gigahertz(x) => assert_eq!(x, 2.4),
+            _ => panic!("expected Frequency::Gigahertz variant"),
+        }
+        let hz = Frequency::Hertz(1.0);
+        assert!((hz.to(FrequencyUnit::per_h).value() - 3600.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cmp() {
+        assert!(Frequency::Megahertz(1.0) > Frequency::Kilohertz(999.0));
+        assert!(Frequency::PerMinute(61.0) > Frequency::Hertz(1.0));
+        assert_eq!(Frequency::RevolutionsPerMinute(60.0), Frequency::Hertz(1.0));
+        assert_eq!(Frequency::Hertz(2.603e8).to(FrequencyUnit::Gigahertz), Frequency::Hertz(2.603e8));
+        assert_eq!(Frequency::Hertz(5.343e8).to(FrequencyUnit::Megahertz), Frequency::Hertz(5.343e8));
+    }
+
+    #[test]
+    fn test_add_sub() {
+        match Frequency::Kilohertz(1.0) + Frequency::Hertz(500.0) {
+            Frequency::Kilohertz(x) => assert_eq!(x, 1.5),
+            _ => panic!("expected Frequency::Kilohertz variant"),
+        }
+        match Frequency::Hertz(10.0) - Frequency::PerMinute(300.0) {
+            Frequency::Hertz(x) => assert_eq!(x, 5.0),
+            _ => panic!("expected Frequency::Hertz variant"),
+        }
+    }
+
+    #[test]
+    fn test_scalar_ops() {
+        let clock = Frequency::Megahertz(100.0);
+        assert_eq!(clock * 2.0, Frequency::Megahertz(200.0));
+        assert_eq!(2.0 * clock, Frequency::Megahertz(200.0));
+        assert_eq!(clock / 4.0, Frequency::Megahertz(25.0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Frequency::Hertz(50.0)), "50 Hz");
+        assert_eq!(format!("{}", Frequency::Gigahertz(2.4)), "2.4 GHz");
+        assert_eq!(format!("{}", Frequency::RevolutionsPerMinute(7200.0)), "7200 rpm");
+        assert_eq!(format!("{}", Frequency::PerHour(4.0)), "4 /h");
+    }
+
+    #[test]
+    fn test_reciprocal_time() {
+        match 1.0 / Time::Seconds(0.01) {
+            Frequency::Hertz(x) => assert_eq!(x, 100.0),
+            _ => panic!("expected Frequency::Hertz variant"),
+        }
+        match 30.0 / Time::Minutes(1.0) {
+            Frequency::PerMinute(x) => assert_eq!(x, 30.0),
+            _ => panic!("expected Frequency::PerMinute variant"),
+        }
+        let rate = 1.0 / Time::Days(1.0);
+        assert!((rate.to(FrequencyUnit::per_h).value() - 1.0 / 24.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cycle_count() {
+        let samples = Frequency::Kilohertz(1.0) * Time::Minutes(1.0);
+        assert_eq!(samples, 60_000.0);
+        let samples = Time::Hours(2.0) * Frequency::PerMinute(3.0);
+        assert_eq!(samples, 360.0);
+    }
+
+    #[test]
+    fn test_period() {
+        match Frequency::Hertz(50.0).period() {
+            Time::Seconds(x) => assert_eq!(x, 0.02),
+            _ => panic!("expected Time::Seconds variant"),
+        }
+        match Frequency::PerHour(4.0).period() {
+            Time::Hours(x) => assert_eq!(x, 0.25),
+            _ => panic!("expected Time::Hours variant"),
+        }
+        let period = Frequency::Megahertz(1.0).period();
+        assert!((period.to(TimeUnit::Seconds).value() - 1.0e-6).abs() < 1e-18);
+    }
+}
@@ -1,7 +1,10 @@
-use std::ops::{Add, Sub, Mul, Div};
+use std::ops::{Mul, Div};
 use std::fmt::Display;
+use std::str::FromStr;
 use crate::unit_traits::UnitConversion;
 use crate::unit_traits::Unit;
+use crate::parse::{split_quantity, ParseUnitError};
+use crate::utils;
 
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -21,6 +24,8 @@ pub enum LengthUnit {
     inch,
     Yards,
     yd,
+    Miles,
+    mi,
     NauticalMiles,
     nmi,
 }
@@ -34,9 +39,22 @@ pub enum Length {
     Feet(f64),
     Inches(f64),
     Yards(f64),
+    Miles(f64),
     NauticalMiles(f64),
 }
 
+impl Length {
+    pub const METERS_PER_MILLIMETER: f64 = 0.001;
+    pub const METERS_PER_CENTIMETER: f64 = 0.01;
+    pub const METERS_PER_KILOMETER: f64 = 1_000.0;
+    // The international yard and pound agreement (1959) defines these exactly.
+    pub const METERS_PER_INCH: f64 = 0.0254;
+    pub const METERS_PER_FOOT: f64 = 0.3048;
+    pub const METERS_PER_YARD: f64 = 0.9144;
+    pub const METERS_PER_MILE: f64 = 1_609.344;
+    pub const METERS_PER_NAUTICAL_MILE: f64 = 1_852.0;
+}
+
 impl Unit for Length {
     fn value(&self) -> f64 {
         match self {
@@ -47,40 +65,42 @@ impl Unit for Length {
             Length::Feet(val)          => *val,
             Length::Inches(val)        => *val,
             Length::Yards(val)         => *val,
+            Length::Miles(val)         => *val,
             Length::NauticalMiles(val) => *val,
         }
     }
 }
 
-impl PartialEq for Length {
-    fn eq(&self, other: &Length) -> bool {
-        let a = self.to_base().value();
-        let b = other.to_base().value();
-        (a - b).abs() < f64::EPSILON
-    }
-}
-
-impl PartialOrd for Length {
-    fn partial_cmp(&self, other: &Length) -> Option<std::cmp::Ordering> {
-        let a = self.to_base().value();
-        let b = other.to_base().value();
-        (a - b).partial_cmp(&0.0)
-    }
-}
 
 impl UnitConversion for Length {
     type Units = LengthUnit;
 
+    /// Returns the unit the Length is expressed in.
+    fn unit(&self) -> LengthUnit {
+        match self {
+            Length::Millimeters(_) => LengthUnit::Millimeters,
+            Length::Centimeters(_) => LengthUnit::Centimeters,
+            Length::Meters(_) => LengthUnit::Meters,
+            Length::Kilometers(_) => LengthUnit::Kilometers,
+            Length::Feet(_) => LengthUnit::Feet,
+            Length::Inches(_) => LengthUnit::Inches,
+            Length::Yards(_) => LengthUnit::Yards,
+            Length::Miles(_) => LengthUnit::Miles,
+            Length::NauticalMiles(_) => LengthUnit::NauticalMiles,
+        }
+    }
+
     fn to_base(&self) -> Length {
         let value = match self {
-            Length::Millimeters(val)   => val * 1000.0,
-            Length::Centimeters(val)   => val * 10.0,
+            Length::Millimeters(val)   => val * Length::METERS_PER_MILLIMETER,
+            Length::Centimeters(val)   => val * Length::METERS_PER_CENTIMETER,
             Length::Meters(val)        => *val,
-            Length::Kilometers(val)    => val * 1_000.0,
-            Length::Feet(val)          => val / 3.28084,
-            Length::Inches(val)        => val / 39.3701,
-            Length::Yards(val)         => val / 1.09361,
-            Length::NauticalMiles(val) => val * 1_852.0,
+            Length::Kilometers(val)    => val * Length::METERS_PER_KILOMETER,
+            Length::Feet(val)          => val * Length::METERS_PER_FOOT,
+            Length::Inches(val)        => val * Length::METERS_PER_INCH,
+            Length::Yards(val)         => val * Length::METERS_PER_YARD,
+            Length::Miles(val)         => val * Length::METERS_PER_MILE,
+            Length::NauticalMiles(val) => val * Length::METERS_PER_NAUTICAL_MILE,
         };
         Length::Meters(value)
     }
@@ -89,14 +109,15 @@ impl UnitConversion for Length {
         use LengthUnit::*;
         let base = self.to_base().value();
         match variant {
-            Millimeters   | mm   => Length::Millimeters(base * 1000.0),
-            Centimeters   | cm   => Length::Centimeters(base * 10.0),
+            Millimeters   | mm   => Length::Millimeters(base / Length::METERS_PER_MILLIMETER),
+            Centimeters   | cm   => Length::Centimeters(base / Length::METERS_PER_CENTIMETER),
             Meters        | m    => Length::Meters(base),
-            Kilometers    | km   => Length::Kilometers(base / 1_000.0),
-            Feet          | ft   => Length::Feet(base * 3.28084),
-            Inches        | inch => Length::Inches(base * 39.3701),
-            Yards         | yd   => Length::Yards(base * 1.09361),
-            NauticalMiles | nmi  => Length::NauticalMiles(base / 1_852.0),
+            Kilometers    | km   => Length::Kilometers(base / Length::METERS_PER_KILOMETER),
+            Feet          | ft   => Length::Feet(base / Length::METERS_PER_FOOT),
+            Inches        | inch => Length::Inches(base / Length::METERS_PER_INCH),
+            Yards         | yd   => Length::Yards(base / Length::METERS_PER_YARD),
+            Miles         | mi   => Length::Miles(base / Length::METERS_PER_MILE),
+            NauticalMiles | nmi  => Length::NauticalMiles(base / Length::METERS_PER_NAUTICAL_MILE),
         }
     }
 }
@@ -104,73 +125,56 @@ impl UnitConversion for Length {
 
 impl Display for Length {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        match self {
-            Length::Millimeters(val)   => write!(f, "{} mm", val),
-            Length::Centimeters(val)   => write!(f, "{} cm", val),
-            Length::Meters(val)        => write!(f, "{} m", val),
-            Length::Kilometers(val)    => write!(f, "{} km", val),
-            Length::Feet(val)          => write!(f, "{} ft", val),
-            Length::Inches(val)        => write!(f, "{} in", val),
-            Length::Yards(val)         => write!(f, "{} yd", val),
-            Length::NauticalMiles(val) => write!(f, "{} nmi", val),
-        }
-    }
-}
-
-impl Add for Length {
-    type Output = Length;
-
-    fn add(self, other: Length) -> Length {
-        let other_val = match self {
-            Length::Millimeters(_) => other.to(LengthUnit::Millimeters).value(),
-            Length::Centimeters(_) => other.to(LengthUnit::Centimeters).value(),
-            Length::Meters(_)      => other.to(LengthUnit::Meters).value(),
-            Length::Kilometers(_)  => other.to(LengthUnit::Kilometers).value(),
-            Length::Feet(_)        => other.to(LengthUnit::Feet).value(),
-            Length::Inches(_)      => other.to(LengthUnit::Inches).value(),
-            Length::Yards(_)       => other.to(LengthUnit::Yards).value(),
-            Length::NauticalMiles(_) => other.to(LengthUnit::NauticalMiles).value(),
+        let (val, symbol, name) = match self {
+            Length::Millimeters(val)   => (val, "mm", "millimeters"),
+            Length::Centimeters(val)   => (val, "cm", "centimeters"),
+            Length::Meters(val)        => (val, "m", "meters"),
+            Length::Kilometers(val)    => (val, "km", "kilometers"),
+            Length::Feet(val)          => (val, "ft", "feet"),
+            Length::Inches(val)        => (val, "in", "inches"),
+            Length::Yards(val)         => (val, "yd", "yards"),
+            Length::Miles(val)         => (val, "mi", "miles"),
+            Length::NauticalMiles(val) => (val, "nmi", "nautical miles"),
         };
-        match self {
-            Length::Millimeters(val) => Length::Millimeters(val + other_val),
-            Length::Centimeters(val) => Length::Centimeters(val + other_val),
-            Length::Meters(val)      => Length::Meters(val + other_val),
-            Length::Kilometers(val)  => Length::Kilometers(val + other_val),
-            Length::Feet(val)        => Length::Feet(val + other_val),
-            Length::Inches(val)      => Length::Inches(val + other_val),
-            Length::Yards(val)       => Length::Yards(val + other_val),
-            Length::NauticalMiles(val) => Length::NauticalMiles(val + other_val),
-        }
+        utils::fmt_quantity(f, *val, symbol, name)
     }
 }
 
-impl Sub for Length {
-    type Output = Length;
+impl FromStr for Length {
+    type Err = ParseUnitError;
 
-    fn sub(self, other: Length) -> Length {
-        let other_val = match self {
-            Length::Millimeters(_) => other.to(LengthUnit::Millimeters).value(),
-            Length::Centimeters(_) => other.to(LengthUnit::Centimeters).value(),
-            Length::Meters(_)      => other.to(LengthUnit::Meters).value(),
-            Length::Kilometers(_)  => other.to(LengthUnit::Kilometers).value(),
-            Length::Feet(_)        => other.to(LengthUnit::Feet).value(),
-            Length::Inches(_)      => other.to(LengthUnit::Inches).value(),
-            Length::Yards(_)       => other.to(LengthUnit::Yards).value(),
-            Length::NauticalMiles(_) => other.to(LengthUnit::NauticalMiles).value(),
+    /// Parses strings such as `"30 km"`, `"12ft"` or `"3.5 nautical miles"`. Unit symbols are
+    /// case sensitive while spelled-out unit names are not.
+    fn from_str(s: &str) -> Result<Length, ParseUnitError> {
+        let (x, unit) = split_quantity(s)?;
+        let length = match unit {
+            "mm"  => Length::Millimeters(x),
+            "cm"  => Length::Centimeters(x),
+            "m"   => Length::Meters(x),
+            "km"  => Length::Kilometers(x),
+            "ft"  => Length::Feet(x),
+            "in" | "inch" => Length::Inches(x),
+            "yd"  => Length::Yards(x),
+            "mi"  => Length::Miles(x),
+            "nmi" => Length::NauticalMiles(x),
+            _ => match unit.to_lowercase().as_str() {
+                "millimeter" | "millimeters" | "millimetre" | "millimetres" => Length::Millimeters(x),
+                "centimeter" | "centimeters" | "centimetre" | "centimetres" => Length::Centimeters(x),
+                "meter" | "meters" | "metre" | "metres" => Length::Meters(x),
+                "kilometer" | "kilometers" | "kilometre" | "kilometres" => Length::Kilometers(x),
+                "foot" | "feet" => Length::Feet(x),
+                "inches" => Length::Inches(x),
+                "yard" | "yards" => Length::Yards(x),
+                "mile" | "miles" => Length::Miles(x),
+                "nauticalmile" | "nauticalmiles" | "nautical mile" | "nautical miles" => Length::NauticalMiles(x),
+                _ => return Err(ParseUnitError::UnknownUnit(unit.to_string())),
+            },
         };
-        match self {
-            Length::Millimeters(val) => Length::Millimeters(val - other_val),
-            Length::Centimeters(val) => Length::Centimeters(val - other_val),
-            Length::Meters(val)      => Length::Meters(val - other_val),
-            Length::Kilometers(val)  => Length::Kilometers(val - other_val),
-            Length::Feet(val)        => Length::Feet(val - other_val),
-            Length::Inches(val)      => Length::Inches(val - other_val),
-            Length::Yards(val)       => Length::Yards(val - other_val),
-            Length::NauticalMiles(val) => Length::NauticalMiles(val - other_val),
-        }
+        Ok(length)
     }
 }
 
+
 impl Mul<f64> for Length {
     type Output = Length;
 
@@ -183,6 +187,7 @@ impl Mul<f64> for Length {
             Length::Feet(val)        => Length::Feet(val * rhs),
             Length::Inches(val)      => Length::Inches(val * rhs),
             Length::Yards(val)       => Length::Yards(val * rhs),
+            Length::Miles(val)       => Length::Miles(val * rhs),
             Length::NauticalMiles(val) => Length::NauticalMiles(val * rhs),
         }
     }
@@ -200,6 +205,7 @@ impl Mul<Length> for f64 {
             Length::Feet(val)        => Length::Feet(val * self),
             Length::Inches(val)      => Length::Inches(val * self),
             Length::Yards(val)       => Length::Yards(val * self),
+            Length::Miles(val)       => Length::Miles(val * self),
             Length::NauticalMiles(val) => Length::NauticalMiles(val * self),
         }
     }
@@ -218,6 +224,7 @@ impl Div<f64> for Length {
             Length::Feet(val)        => Length::Feet(val / rhs),
             Length::Inches(val)      => Length::Inches(val / rhs),
             Length::Yards(val)       => Length::Yards(val / rhs),
+            Length::Miles(val)       => Length::Miles(val / rhs),
             Length::NauticalMiles(val) => Length::NauticalMiles(val / rhs),
         }
     }
@@ -226,6 +233,7 @@ impl Div<f64> for Length {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::assert_close;
 
     #[test]
     fn test_length() {
@@ -263,4 +271,108 @@ mod tests {
         let length_in_km = length.to(LengthUnit::Kilometers).value();
         assert_eq!(length_in_km, 0.002);
     }
-}
\ No newline at end of file
+
+    /// Every unit name alongside the exact number of meters in one of that unit.
+    const UNIT_TABLE: [(LengthUnit, f64); 18] = [
+        (LengthUnit::Millimeters, 0.001),
+        (LengthUnit::mm, 0.001),
+        (LengthUnit::Centimeters, 0.01),
+        (LengthUnit::cm, 0.01),
+        (LengthUnit::Meters, 1.0),
+        (LengthUnit::m, 1.0),
+        (LengthUnit::Kilometers, 1000.0),
+        (LengthUnit::km, 1000.0),
+        (LengthUnit::Feet, 0.3048),
+        (LengthUnit::ft, 0.3048),
+        (LengthUnit::Inches, 0.0254),
+        (LengthUnit::inch, 0.0254),
+        (LengthUnit::Yards, 0.9144),
+        (LengthUnit::yd, 0.9144),
+        (LengthUnit::Miles, 1609.344),
+        (LengthUnit::mi, 1609.344),
+        (LengthUnit::NauticalMiles, 1852.0),
+        (LengthUnit::nmi, 1852.0),
+    ];
+
+    #[test]
+    fn test_to_base_table() {
+        for (unit, meters) in UNIT_TABLE {
+            let one = Length::Meters(meters).to(unit);
+            assert_close(one.value(), 1.0);
+            match one.to_base() {
+                Length::Meters(x) => assert_close(x, meters),
+                _ => panic!("expected Length::Meters variant"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_conversion_table() {
+        for (from, from_meters) in UNIT_TABLE {
+            for (to, to_meters) in UNIT_TABLE {
+                let x = Length::Meters(from_meters).to(from);
+                assert_close(x.to(to).value(), from_meters / to_meters);
+            }
+        }
+    }
+
+    #[test]
+    fn test_millimeters() {
+        assert_eq!(Length::Millimeters(1000.0), Length::Meters(1.0));
+        assert_eq!(Length::Centimeters(100.0), Length::Meters(1.0));
+        assert_eq!(Length::Millimeters(10.0), Length::Centimeters(1.0));
+        match Length::Meters(1.0) + Length::Millimeters(500.0) {
+            Length::Meters(x) => assert_eq!(x, 1.5),
+            _ => panic!("expected Length::Meters variant"),
+        }
+    }
+
+    #[test]
+    fn test_imperial_relations() {
+        assert_close(Length::Feet(1.0).to(LengthUnit::Inches).value(), 12.0);
+        assert_close(Length::Yards(1.0).to(LengthUnit::Feet).value(), 3.0);
+        assert_close(Length::Miles(1.0).to(LengthUnit::Yards).value(), 1760.0);
+        assert_close(Length::Miles(1.0).to(LengthUnit::Feet).value(), 5280.0);
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("30 km".parse::<Length>(), Ok(Length::Kilometers(30.0)));
+        assert_eq!("12ft".parse::<Length>(), Ok(Length::Feet(12.0)));
+        assert_eq!("6 inch".parse::<Length>(), Ok(Length::Inches(6.0)));
+        assert_eq!("2 Metres".parse::<Length>(), Ok(Length::Meters(2.0)));
+        assert_eq!("3.5 nautical miles".parse::<Length>(), Ok(Length::NauticalMiles(3.5)));
+        assert_eq!("26.2 NauticalMiles".parse::<Length>(), Ok(Length::NauticalMiles(26.2)));
+        match "1 mi".parse::<Length>() {
+            Ok(Length::Miles(x)) => assert_eq!(x, 1.0),
+            _ => panic!("expected Length::Miles variant"),
+        }
+    }
+
+    #[test]
+    fn test_from_str_round_trip() {
+        for (unit, meters) in UNIT_TABLE {
+            let length = Length::Meters(meters * 2.5).to(unit);
+            let parsed: Length = length.to_string().parse().unwrap();
+            assert_eq!(format!("{}", parsed), format!("{}", length));
+        }
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        assert_eq!("3 M".parse::<Length>(), Err(ParseUnitError::UnknownUnit("M".to_string())));
+        assert_eq!("3 furlongs".parse::<Length>(), Err(ParseUnitError::UnknownUnit("furlongs".to_string())));
+        assert_eq!("km".parse::<Length>(), Err(ParseUnitError::InvalidNumber("km".to_string())));
+    }
+
+    #[test]
+    fn test_display_format() {
+        let x = Length::Kilometers(12.3456);
+        assert_eq!(format!("{:.2}", x), "12.35 km");
+        assert_eq!(format!("{:#.1}", x), "12.3 kilometers");
+        assert_eq!(format!("{:>12.1}", x), "     12.3 km");
+        assert_eq!(format!("{:<12.1}|", x), "12.3 km     |");
+        assert_eq!(format!("{:+}", Length::Feet(3.0)), "+3 ft");
+        assert_eq!(format!("{:#}", Length::NauticalMiles(2.0)), "2 nautical miles");
+    }
+}
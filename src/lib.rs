@@ -2,9 +2,26 @@
 
 // The unit types
 pub use length::Length;
-pub use time::Time;
+pub use time::{Time, DurationError};
 pub use data::Data;
 pub use data_rate::DataRate;
+pub use area::Area;
+pub use volume::Volume;
+pub use speed::Speed;
+pub use acceleration::Acceleration;
+pub use mass::Mass;
+pub use force::Force;
+pub use pressure::Pressure;
+pub use energy::Energy;
+pub use power::Power;
+pub use temperature::{Temperature, TemperatureDelta};
+pub use frequency::Frequency;
+pub use angle::Angle;
+pub use angular_speed::AngularSpeed;
+pub use angular_acceleration::AngularAcceleration;
+pub use parse::ParseUnitError;
+pub use calendar::{CalendarSpan, CivilDate};
+pub use quantity::Quantity;
 
 // The unit name enums
 pub mod unit {
@@ -16,6 +33,20 @@ pub mod unit {
     pub use crate::time::TimeUnit::*;
     pub use crate::data::DataUnit::*;
     pub use crate::data_rate::DataRateUnit::*;
+    pub use crate::area::AreaUnit::*;
+    pub use crate::volume::VolumeUnit::*;
+    pub use crate::speed::SpeedUnit::*;
+    pub use crate::acceleration::AccelerationUnit::*;
+    pub use crate::mass::MassUnit::*;
+    pub use crate::force::ForceUnit::*;
+    pub use crate::pressure::PressureUnit::*;
+    pub use crate::energy::EnergyUnit::*;
+    pub use crate::power::PowerUnit::*;
+    pub use crate::temperature::TemperatureUnit::*;
+    pub use crate::frequency::FrequencyUnit::*;
+    pub use crate::angle::AngleUnit::*;
+    pub use crate::angular_speed::AngularSpeedUnit::*;
+    pub use crate::angular_acceleration::AngularAccelerationUnit::*;
 }
 
 // Prelude
@@ -26,9 +57,27 @@ pub mod length;
 pub mod time;
 pub mod data;
 pub mod data_rate;
+pub mod area;
+pub mod volume;
+pub mod speed;
+pub mod acceleration;
+pub mod mass;
+pub mod force;
+pub mod pressure;
+pub mod energy;
+pub mod power;
+pub mod temperature;
+pub mod frequency;
+pub mod angle;
+pub mod angular_speed;
+pub mod angular_acceleration;
+pub mod calendar;
+pub mod dimension;
+pub mod quantity;
 
 // Module declarations - internal modules
 mod unit_traits;
 mod utils;
+mod parse;
 
 
@@ -0,0 +1,371 @@
+//! This module contains the `Mass` enum and its associated unit conversions.
+//! The `Mass` enum is used to represent masses in grams, kilograms, pounds, etc.
+//! The `MassUnit` enum is used to specify the unit of the mass.
+//! The `Mass` enum implements the `Unit` and `UnitConversion` traits, which offer the ability to 
+//! convert between different units of mass via the `to_base` and `to` methods.
+
+use std::ops::{Mul, Div};
+use std::fmt::Display;
+use crate::unit_traits::UnitConversion;
+use crate::unit_traits::Unit;
+use crate::utils;
+
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[allow(non_camel_case_types)]
+pub enum MassUnit {
+    Milligrams,
+    mg,
+    Grams,
+    g,
+    Kilograms,
+    kg,
+    Tonnes,
+    t,
+    Grains,
+    gr,
+    Ounces,
+    oz,
+    Pounds,
+    lb,
+    Stones,
+    st,
+    ShortTons,
+    short_ton,
+    LongTons,
+    long_ton,
+    AtomicMassUnits,
+    Da,
+}
+
+
+/// The `Mass` enum represents masses in grams, kilograms, pounds, etc.
+#[derive(Debug, Clone, Copy)]
+pub enum Mass {
+    Milligrams(f64),
+    Grams(f64),
+    Kilograms(f64),
+    Tonnes(f64),
+    Grains(f64),
+    Ounces(f64),
+    Pounds(f64),
+    Stones(f64),
+    ShortTons(f64),
+    LongTons(f64),
+    AtomicMassUnits(f64),
+}
+
+impl Mass {
+    pub const KILOGRAMS_PER_MILLIGRAM: f64 = 1.0e-6;
+    pub const KILOGRAMS_PER_GRAM: f64 = 1.0e-3;
+    pub const KILOGRAMS_PER_TONNE: f64 = 1000.0;
+    pub const KILOGRAMS_PER_GRAIN: f64 = 64.798_91e-6;
+    pub const KILOGRAMS_PER_OUNCE: f64 = 0.028_349_523_125;
+    pub const KILOGRAMS_PER_POUND: f64 = 0.453_592_37;
+    pub const KILOGRAMS_PER_STONE: f64 = 6.350_293_18;
+    pub const KILOGRAMS_PER_SHORT_TON: f64 = 907.184_74;
+    pub const KILOGRAMS_PER_LONG_TON: f64 = 1_016.046_908_8;
+    pub const KILOGRAMS_PER_DALTON: f64 = 1.660_539_066_60e-27;
+
+    /* ALIASES */
+
+    pub fn mg(x: f64) -> Mass {
+        Mass::Milligrams(x)
+    }
+
+    pub fn g(x: f64) -> Mass {
+        Mass::Grams(x)
+    }
+
+    pub fn kg(x: f64) -> Mass {
+        Mass::Kilograms(x)
+    }
+
+    pub fn t(x: f64) -> Mass {
+        Mass::Tonnes(x)
+    }
+
+    pub fn gr(x: f64) -> Mass {
+        Mass::Grains(x)
+    }
+
+    pub fn oz(x: f64) -> Mass {
+        Mass::Ounces(x)
+    }
+
+    pub fn lb(x: f64) -> Mass {
+        Mass::Pounds(x)
+    }
+
+    pub fn st(x: f64) -> Mass {
+        Mass::Stones(x)
+    }
+
+    pub fn short_ton(x: f64) -> Mass {
+        Mass::ShortTons(x)
+    }
+
+    pub fn long_ton(x: f64) -> Mass {
+        Mass::LongTons(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn Da(x: f64) -> Mass {
+        Mass::AtomicMassUnits(x)
+    }
+}
+
+impl Unit for Mass {
+    /// Returns the inner f64 value of the Mass enum.
+    fn value(&self) -> f64 {
+        match self {
+            Mass::Milligrams(x) => *x,
+            Mass::Grams(x) => *x,
+            Mass::Kilograms(x) => *x,
+            Mass::Tonnes(x) => *x,
+            Mass::Grains(x) => *x,
+            Mass::Ounces(x) => *x,
+            Mass::Pounds(x) => *x,
+            Mass::Stones(x) => *x,
+            Mass::ShortTons(x) => *x,
+            Mass::LongTons(x) => *x,
+            Mass::AtomicMassUnits(x) => *x,
+        }
+    }
+}
+
+impl UnitConversion for Mass {
+    type Units = MassUnit;
+
+    /// Returns the unit the Mass is expressed in.
+    fn unit(&self) -> MassUnit {
+        match self {
+            Mass::Milligrams(_) => MassUnit::Milligrams,
+            Mass::Grams(_) => MassUnit::Grams,
+            Mass::Kilograms(_) => MassUnit::Kilograms,
+            Mass::Tonnes(_) => MassUnit::Tonnes,
+            Mass::Grains(_) => MassUnit::Grains,
+            Mass::Ounces(_) => MassUnit::Ounces,
+            Mass::Pounds(_) => MassUnit::Pounds,
+            Mass::Stones(_) => MassUnit::Stones,
+            Mass::ShortTons(_) => MassUnit::ShortTons,
+            Mass::LongTons(_) => MassUnit::LongTons,
+            Mass::AtomicMassUnits(_) => MassUnit::AtomicMassUnits,
+        }
+    }
+
+    /// Converts a given instance of a Mass enum into the base variant, `Mass::Kilograms`.
+    fn to_base(&self) -> Mass {
+        match self {
+            Mass::Milligrams(x) => Mass::Kilograms(x * Mass::KILOGRAMS_PER_MILLIGRAM),
+            Mass::Grams(x) => Mass::Kilograms(x * Mass::KILOGRAMS_PER_GRAM),
+            Mass::Kilograms(x) => Mass::Kilograms(*x),
+            Mass::Tonnes(x) => Mass::Kilograms(x * Mass::KILOGRAMS_PER_TONNE),
+            Mass::Grains(x) => Mass::Kilograms(x * Mass::KILOGRAMS_PER_GRAIN),
+            Mass::Ounces(x) => Mass::Kilograms(x * Mass::KILOGRAMS_PER_OUNCE),
+            Mass::Pounds(x) => Mass::Kilograms(x * Mass::KILOGRAMS_PER_POUND),
+            Mass::Stones(x) => Mass::Kilograms(x * Mass::KILOGRAMS_PER_STONE),
+            Mass::ShortTons(x) => Mass::Kilograms(x * Mass::KILOGRAMS_PER_SHORT_TON),
+            Mass::LongTons(x) => Mass::Kilograms(x * Mass::KILOGRAMS_PER_LONG_TON),
+            Mass::AtomicMassUnits(x) => Mass::Kilograms(x * Mass::KILOGRAMS_PER_DALTON),
+        }
+    }
+
+    /// Converts a given instance of a Mass enum into the specified variant as indicated by
+    /// the `MassUnit` provided.
+    fn to(&self, variant: MassUnit) -> Mass {
+        let base = self.to_base().value();
+        match variant {
+            MassUnit::Milligrams | MassUnit::mg => Mass::Milligrams(base / Mass::KILOGRAMS_PER_MILLIGRAM),
+            MassUnit::Grams | MassUnit::g => Mass::Grams(base / Mass::KILOGRAMS_PER_GRAM),
+            MassUnit::Kilograms | MassUnit::kg => Mass::Kilograms(base),
+            MassUnit::Tonnes | MassUnit::t => Mass::Tonnes(base / Mass::KILOGRAMS_PER_TONNE),
+            MassUnit::Grains | MassUnit::gr => Mass::Grains(base / Mass::KILOGRAMS_PER_GRAIN),
+            MassUnit::Ounces | MassUnit::oz => Mass::Ounces(base / Mass::KILOGRAMS_PER_OUNCE),
+            MassUnit::Pounds | MassUnit::lb => Mass::Pounds(base / Mass::KILOGRAMS_PER_POUND),
+            MassUnit::Stones | MassUnit::st => Mass::Stones(base / Mass::KILOGRAMS_PER_STONE),
+            MassUnit::ShortTons | MassUnit::short_ton => Mass::ShortTons(base / Mass::KILOGRAMS_PER_SHORT_TON),
+            MassUnit::LongTons | MassUnit::long_ton => Mass::LongTons(base / Mass::KILOGRAMS_PER_LONG_TON),
+            MassUnit::AtomicMassUnits | MassUnit::Da => Mass::AtomicMassUnits(base / Mass::KILOGRAMS_PER_DALTON),
+        }
+    }
+}
+
+
+impl Mul<f64> for Mass {
+    type Output = Mass;
+
+    fn mul(self, other: f64) -> Mass {
+        match self {
+            Mass::Milligrams(x) => Mass::Milligrams(x * other),
+            Mass::Grams(x) => Mass::Grams(x * other),
+            Mass::Kilograms(x) => Mass::Kilograms(x * other),
+            Mass::Tonnes(x) => Mass::Tonnes(x * other),
+            Mass::Grains(x) => Mass::Grains(x * other),
+            Mass::Ounces(x) => Mass::Ounces(x * other),
+            Mass::Pounds(x) => Mass::Pounds(x * other),
+            Mass::Stones(x) => Mass::Stones(x * other),
+            Mass::ShortTons(x) => Mass::ShortTons(x * other),
+            Mass::LongTons(x) => Mass::LongTons(x * other),
+            Mass::AtomicMassUnits(x) => Mass::AtomicMassUnits(x * other),
+        }
+    }
+}
+
+
+impl Mul<Mass> for f64 {
+    type Output = Mass;
+
+    fn mul(self, other: Mass) -> Mass {
+        match other {
+            Mass::Milligrams(x) => Mass::Milligrams(x * self),
+            Mass::Grams(x) => Mass::Grams(x * self),
+            Mass::Kilograms(x) => Mass::Kilograms(x * self),
+            Mass::Tonnes(x) => Mass::Tonnes(x * self),
+            Mass::Grains(x) => Mass::Grains(x * self),
+            Mass::Ounces(x) => Mass::Ounces(x * self),
+            Mass::Pounds(x) => Mass::Pounds(x * self),
+            Mass::Stones(x) => Mass::Stones(x * self),
+            Mass::ShortTons(x) => Mass::ShortTons(x * self),
+            Mass::LongTons(x) => Mass::LongTons(x * self),
+            Mass::AtomicMassUnits(x) => Mass::AtomicMassUnits(x * self),
+        }
+    }
+}
+
+
+impl Div<f64> for Mass {
+    type Output = Mass;
+
+    fn div(self, other: f64) -> Mass {
+        match self {
+            Mass::Milligrams(x) => Mass::Milligrams(x / other),
+            Mass::Grams(x) => Mass::Grams(x / other),
+            Mass::Kilograms(x) => Mass::Kilograms(x / other),
+            Mass::Tonnes(x) => Mass::Tonnes(x / other),
+            Mass::Grains(x) => Mass::Grains(x / other),
+            Mass::Ounces(x) => Mass::Ounces(x / other),
+            Mass::Pounds(x) => Mass::Pounds(x / other),
+            Mass::Stones(x) => Mass::Stones(x / other),
+            Mass::ShortTons(x) => Mass::ShortTons(x / other),
+            Mass::LongTons(x) => Mass::LongTons(x / other),
+            Mass::AtomicMassUnits(x) => Mass::AtomicMassUnits(x / other),
+        }
+    }
+}
+
+
+impl Display for Mass {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (x, symbol, name) = match self {
+            Mass::Milligrams(x) => (x, "mg", "milligrams"),
+            Mass::Grams(x) => (x, "g", "grams"),
+            Mass::Kilograms(x) => (x, "kg", "kilograms"),
+            Mass::Tonnes(x) => (x, "t", "tonnes"),
+            Mass::Grains(x) => (x, "gr", "grains"),
+            Mass::Ounces(x) => (x, "oz", "ounces"),
+            Mass::Pounds(x) => (x, "lb", "pounds"),
+            Mass::Stones(x) => (x, "st", "stones"),
+            Mass::ShortTons(x) => (x, "short tn", "short tons"),
+            Mass::LongTons(x) => (x, "long tn", "long tons"),
+            Mass::AtomicMassUnits(x) => (x, "Da", "atomic mass units"),
+        };
+        utils::fmt_quantity(f, *x, symbol, name)
+    }
+}
+
+
+/*--------------------( Unit Tests )--------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_base() {
+        let mass = Mass::Grams(1500.0);
+        match mass.to_base() {
+            Mass::Kilograms(x) => assert_eq!(x, 1.5),
+            _ => panic!("expected Mass::Kilograms variant"),
+        }
+        assert_eq!(Mass::Pounds(1.0).to_base().value(), Mass::KILOGRAMS_PER_POUND);
+    }
+
+    #[test]
+    fn test_from_base() {
+        let mass = Mass::Kilograms(2.5);
+        match mass.to(MassUnit::t) {
+            Mass::Tonnes(x) => assert_eq!(x, 0.0025),
+            _ => panic!("expected Mass::Tonnes variant"),
+        }
+        match mass.to(MassUnit::g) {
+            Mass::Grams(x) => assert_eq!(x, 2500.0),
+            _ => panic!("expected Mass::Grams variant"),
+        }
+    }
+
+    #[test]
+    fn test_avoirdupois_conversions() {
+        let pound = Mass::Pounds(1.0);
+        assert!((pound.to(MassUnit::Ounces).value() - 16.0).abs() < 1e-12);
+        assert!((pound.to(MassUnit::Grains).value() - 7000.0).abs() < 1e-9);
+        assert!((Mass::Stones(1.0).to(MassUnit::Pounds).value() - 14.0).abs() < 1e-12);
+        assert!((Mass::ShortTons(1.0).to(MassUnit::Pounds).value() - 2000.0).abs() < 1e-9);
+        assert!((Mass::LongTons(1.0).to(MassUnit::Pounds).value() - 2240.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_atomic_mass_units() {
+        let carbon = Mass::AtomicMassUnits(12.0);
+        let grams = carbon.to(MassUnit::Grams).value();
+        assert!((grams - 1.992_646_879_92e-23).abs() < 1e-33);
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(Mass::Grams(1000.0), Mass::Kilograms(1.0));
+        assert_eq!(Mass::Tonnes(1.0), Mass::Kilograms(1000.0));
+        assert_eq!(Mass::kg(1.0), Mass::Kilograms(1.0));
+
+        // Equality is relative, so it still tells apart masses at atomic scales.
+        assert_ne!(Mass::AtomicMassUnits(1.0), Mass::AtomicMassUnits(1.0e9));
+        assert_ne!(Mass::AtomicMassUnits(12.0), Mass::AtomicMassUnits(13.0));
+        assert_eq!(Mass::AtomicMassUnits(1.0e9), Mass::AtomicMassUnits(1.0e9));
+        assert!(Mass::AtomicMassUnits(1.0) < Mass::AtomicMassUnits(1.0e9));
+    }
+
+    #[test]
+    fn test_cmp() {
+        assert!(Mass::Kilograms(1.0) > Mass::Pounds(2.0));
+        assert!(Mass::LongTons(1.0) > Mass::Tonnes(1.0));
+        assert!(Mass::ShortTons(1.0) < Mass::Tonnes(1.0));
+    }
+
+    #[test]
+    fn test_add_sub() {
+        match Mass::Kilograms(1.0) + Mass::Grams(250.0) {
+            Mass::Kilograms(x) => assert_eq!(x, 1.25),
+            _ => panic!("expected Mass::Kilograms variant"),
+        }
+        match Mass::Pounds(1.0) - Mass::Ounces(8.0) {
+            Mass::Pounds(x) => assert!((x - 0.5).abs() < 1e-12),
+            _ => panic!("expected Mass::Pounds variant"),
+        }
+    }
+
+    #[test]
+    fn test_scalar_ops() {
+        let mass = Mass::Grams(250.0);
+        assert_eq!(mass * 4.0, Mass::Kilograms(1.0));
+        assert_eq!(4.0 * mass, Mass::Kilograms(1.0));
+        assert_eq!(mass / 2.0, Mass::Grams(125.0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Mass::Kilograms(72.5)), "72.5 kg");
+        assert_eq!(format!("{}", Mass::Pounds(160.0)), "160 lb");
+        assert_eq!(format!("{}", Mass::ShortTons(2.0)), "2 short tn");
+        assert_eq!(format!("{}", Mass::AtomicMassUnits(12.0)), "12 Da");
+    }
+}
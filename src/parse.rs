@@ -0,0 +1,178 @@
+//! Shared helpers for parsing quantities such as `"512 MiB"` or `"1.5h"` from strings.
+//! Each unit type implements `FromStr` on top of `split_quantity` and maps the unit token
+//! itself, reporting failures with a `ParseUnitError`.
+
+use std::fmt::Display;
+
+
+/// The error returned when a string cannot be parsed into a unit type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseUnitError {
+    /// The input was empty or contained only whitespace.
+    Empty,
+    /// The numeric part of the input, given here, is not a valid number.
+    InvalidNumber(String),
+    /// The input contained a number but no unit.
+    MissingUnit,
+    /// The unit, given here, is not one the target type recognizes.
+    UnknownUnit(String),
+}
+
+impl Display for ParseUnitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ParseUnitError::Empty => write!(f, "cannot parse a quantity from an empty string"),
+            ParseUnitError::InvalidNumber(token) => write!(f, "invalid number `{}`", token),
+            ParseUnitError::MissingUnit => write!(f, "missing unit after the number"),
+            ParseUnitError::UnknownUnit(token) => write!(f, "unknown unit `{}`", token),
+        }
+    }
+}
+
+impl std::error::Error for ParseUnitError {}
+
+
+/// Splits a string such as `"1.5 GB"` or `"-3e2ms"` into its number and its unit token.
+/// Whitespace around and between the two parts is optional.
+pub(crate) fn split_quantity(s: &str) -> Result<(f64, &str), ParseUnitError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(ParseUnitError::Empty);
+    }
+
+    let end = number_len(s);
+    let (number, unit) = s.split_at(end);
+    if number.is_empty() {
+        let token = s.split_whitespace().next().unwrap_or(s);
+        return Err(ParseUnitError::InvalidNumber(token.to_string()));
+    }
+    let value = number
+        .parse::<f64>()
+        .map_err(|_| ParseUnitError::InvalidNumber(number.to_string()))?;
+
+    let unit = unit.trim();
+    if unit.is_empty() {
+        return Err(ParseUnitError::MissingUnit);
+    }
+    Ok((value, unit))
+}
+
+/// Splits a compound string such as `"1h 30m 15s"` or `"2d4h"` into its components, each a
+/// number followed by a unit token made of letters. Components must be unsigned; a sign applying
+/// to the whole quantity is left to the caller.
+pub(crate) fn split_components(s: &str) -> Result<Vec<(f64, &str)>, ParseUnitError> {
+    let mut rest = s.trim();
+    if rest.is_empty() {
+        return Err(ParseUnitError::Empty);
+    }
+
+    let mut components = Vec::new();
+    while !rest.is_empty() {
+        let end = number_len(rest);
+        let (number, tail) = rest.split_at(end);
+        if number.is_empty() || number.starts_with(['+', '-']) {
+            let token = rest.split_whitespace().next().unwrap_or(rest);
+            return Err(ParseUnitError::InvalidNumber(token.to_string()));
+        }
+        let value = number
+            .parse::<f64>()
+            .map_err(|_| ParseUnitError::InvalidNumber(number.to_string()))?;
+
+        let tail = tail.trim_start();
+        let unit_end = tail.find(|c: char| !c.is_alphabetic()).unwrap_or(tail.len());
+        if unit_end == 0 {
+            return Err(ParseUnitError::MissingUnit);
+        }
+        let (unit, tail) = tail.split_at(unit_end);
+        components.push((value, unit));
+        rest = tail.trim_start();
+    }
+    Ok(components)
+}
+
+/// Returns the length of the leading number in `s`: an optional sign, digits with an optional
+/// decimal point, and an optional exponent. An `e` is only treated as an exponent when digits
+/// follow it, so units starting with `e` are left intact.
+fn number_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let digits = |mut i: usize| {
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        i
+    };
+
+    let mut i = 0;
+    if i < bytes.len() && (bytes[i] == b'+' || bytes[i] == b'-') {
+        i += 1;
+    }
+    i = digits(i);
+    if i < bytes.len() && bytes[i] == b'.' {
+        i = digits(i + 1);
+    }
+    if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+        let mut j = i + 1;
+        if j < bytes.len() && (bytes[j] == b'+' || bytes[j] == b'-') {
+            j += 1;
+        }
+        let k = digits(j);
+        if k > j {
+            i = k;
+        }
+    }
+    i
+}
+
+
+/*--------------------( Unit Tests )--------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_quantity() {
+        assert_eq!(split_quantity("512 MiB"), Ok((512.0, "MiB")));
+        assert_eq!(split_quantity("1.5h"), Ok((1.5, "h")));
+        assert_eq!(split_quantity("  30   km "), Ok((30.0, "km")));
+        assert_eq!(split_quantity("-2.5e3 ms"), Ok((-2500.0, "ms")));
+        assert_eq!(split_quantity("+.5 s"), Ok((0.5, "s")));
+        assert_eq!(split_quantity("10Gbps"), Ok((10.0, "Gbps")));
+        assert_eq!(split_quantity("13.6eV"), Ok((13.6, "eV")));
+        assert_eq!(split_quantity("30 nautical miles"), Ok((30.0, "nautical miles")));
+    }
+
+    #[test]
+    fn test_split_quantity_errors() {
+        assert_eq!(split_quantity(""), Err(ParseUnitError::Empty));
+        assert_eq!(split_quantity("   "), Err(ParseUnitError::Empty));
+        assert_eq!(split_quantity("42"), Err(ParseUnitError::MissingUnit));
+        assert_eq!(split_quantity("MB 12"), Err(ParseUnitError::InvalidNumber("MB".to_string())));
+        assert_eq!(split_quantity("- MB"), Err(ParseUnitError::InvalidNumber("-".to_string())));
+        assert_eq!(split_quantity(". s"), Err(ParseUnitError::InvalidNumber(".".to_string())));
+    }
+
+    #[test]
+    fn test_split_components() {
+        assert_eq!(split_components("1h 30m 15s"), Ok(vec![(1.0, "h"), (30.0, "m"), (15.0, "s")]));
+        assert_eq!(split_components("2d4h"), Ok(vec![(2.0, "d"), (4.0, "h")]));
+        assert_eq!(split_components(" 1.5 min  20 sec "), Ok(vec![(1.5, "min"), (20.0, "sec")]));
+        assert_eq!(split_components("90s"), Ok(vec![(90.0, "s")]));
+    }
+
+    #[test]
+    fn test_split_components_errors() {
+        assert_eq!(split_components(""), Err(ParseUnitError::Empty));
+        assert_eq!(split_components("1h 30"), Err(ParseUnitError::MissingUnit));
+        assert_eq!(split_components("1h -30m"), Err(ParseUnitError::InvalidNumber("-30m".to_string())));
+        assert_eq!(split_components("1h, 30m"), Err(ParseUnitError::InvalidNumber(",".to_string())));
+    }
+
+    #[test]
+    fn test_display() {
+        let err = ParseUnitError::UnknownUnit("Mb".to_string());
+        assert_eq!(format!("{}", err), "unknown unit `Mb`");
+        let err = ParseUnitError::InvalidNumber("1.2.3".to_string());
+        assert_eq!(format!("{}", err), "invalid number `1.2.3`");
+    }
+}
@@ -0,0 +1,398 @@
+//! This module contains the `Power` enum and its associated unit conversions.
+//! The `Power` enum is used to represent powers in watts, kilowatts, horsepower, etc.
+//! The `PowerUnit` enum is used to specify the unit of the power.
+//! The `Power` enum implements the `Unit` and `UnitConversion` traits, which offer the ability to 
+//! convert between different units of power via the `to_base` and `to` methods.
+
+use std::ops::{Mul, Div};
+use std::fmt::Display;
+use crate::unit_traits::UnitConversion;
+use crate::unit_traits::Unit;
+use crate::energy::{Energy, EnergyUnit};
+use crate::time::{Time, TimeUnit};
+use crate::quantity::Quantity;
+use crate::dimension;
+use crate::utils;
+
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[allow(non_camel_case_types)]
+pub enum PowerUnit {
+    Watts,
+    W,
+    Kilowatts,
+    kW,
+    Megawatts,
+    MW,
+    Gigawatts,
+    GW,
+    Horsepower,
+    hp,
+    MetricHorsepower,
+    PS,
+    BtuPerHour,
+    BTU_per_h,
+    ErgsPerSecond,
+    erg_per_s,
+}
+
+
+/// The `Power` enum represents powers in watts, kilowatts, horsepower, etc.
+#[derive(Debug, Clone, Copy)]
+pub enum Power {
+    Watts(f64),
+    Kilowatts(f64),
+    Megawatts(f64),
+    Gigawatts(f64),
+    Horsepower(f64),
+    MetricHorsepower(f64),
+    BtuPerHour(f64),
+    ErgsPerSecond(f64),
+}
+
+impl Power {
+    pub const WATTS_PER_KILOWATT: f64 = 1.0e3;
+    pub const WATTS_PER_MEGAWATT: f64 = 1.0e6;
+    pub const WATTS_PER_GIGAWATT: f64 = 1.0e9;
+    pub const WATTS_PER_HORSEPOWER: f64 = 745.699_871_582_270_2;
+    pub const WATTS_PER_METRIC_HORSEPOWER: f64 = 735.498_75;
+    pub const WATTS_PER_BTU_PER_HOUR: f64 = Energy::JOULES_PER_BTU / Time::SECONDS_PER_HOUR;
+    pub const WATTS_PER_ERG_PER_SECOND: f64 = 1.0e-7;
+
+    /* ALIASES */
+
+    #[allow(non_snake_case)]
+    pub fn W(x: f64) -> Power {
+        Power::Watts(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn kW(x: f64) -> Power {
+        Power::Kilowatts(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn MW(x: f64) -> Power {
+        Power::Megawatts(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn GW(x: f64) -> Power {
+        Power::Gigawatts(x)
+    }
+
+    pub fn hp(x: f64) -> Power {
+        Power::Horsepower(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn PS(x: f64) -> Power {
+        Power::MetricHorsepower(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn BTU_per_h(x: f64) -> Power {
+        Power::BtuPerHour(x)
+    }
+
+    pub fn erg_per_s(x: f64) -> Power {
+        Power::ErgsPerSecond(x)
+    }
+}
+
+impl Unit for Power {
+    /// Returns the inner f64 value of the Power enum.
+    fn value(&self) -> f64 {
+        match self {
+            Power::Watts(x) => *x,
+            Power::Kilowatts(x) => *x,
+            Power::Megawatts(x) => *x,
+            Power::Gigawatts(x) => *x,
+            Power::Horsepower(x) => *x,
+            Power::MetricHorsepower(x) => *x,
+            Power::BtuPerHour(x) => *x,
+            Power::ErgsPerSecond(x) => *x,
+        }
+    }
+}
+
+impl UnitConversion for Power {
+    type Units = PowerUnit;
+
+    /// Returns the unit the Power is expressed in.
+    fn unit(&self) -> PowerUnit {
+        match self {
+            Power::Watts(_) => PowerUnit::Watts,
+            Power::Kilowatts(_) => PowerUnit::Kilowatts,
+            Power::Megawatts(_) => PowerUnit::Megawatts,
+            Power::Gigawatts(_) => PowerUnit::Gigawatts,
+            Power::Horsepower(_) => PowerUnit::Horsepower,
+            Power::MetricHorsepower(_) => PowerUnit::MetricHorsepower,
+            Power::BtuPerHour(_) => PowerUnit::BtuPerHour,
+            Power::ErgsPerSecond(_) => PowerUnit::ErgsPerSecond,
+        }
+    }
+
+    /// Converts a given instance of a Power enum into the base variant, `Power::Watts`.
+    fn to_base(&self) -> Power {
+        match self {
+            Power::Watts(x) => Power::Watts(*x),
+            Power::Kilowatts(x) => Power::Watts(x * Power::WATTS_PER_KILOWATT),
+            Power::Megawatts(x) => Power::Watts(x * Power::WATTS_PER_MEGAWATT),
+            Power::Gigawatts(x) => Power::Watts(x * Power::WATTS_PER_GIGAWATT),
+            Power::Horsepower(x) => Power::Watts(x * Power::WATTS_PER_HORSEPOWER),
+            Power::MetricHorsepower(x) => Power::Watts(x * Power::WATTS_PER_METRIC_HORSEPOWER),
+            Power::BtuPerHour(x) => Power::Watts(x * Power::WATTS_PER_BTU_PER_HOUR),
+            Power::ErgsPerSecond(x) => Power::Watts(x * Power::WATTS_PER_ERG_PER_SECOND),
+        }
+    }
+
+    /// Converts a given instance of a Power enum into the specified variant as indicated by
+    /// the `PowerUnit` provided.
+    fn to(&self, variant: PowerUnit) -> Power {
+        let base = self.to_base().value();
+        match variant {
+            PowerUnit::Watts | PowerUnit::W => Power::Watts(base),
+            PowerUnit::Kilowatts | PowerUnit::kW => Power::Kilowatts(base / Power::WATTS_PER_KILOWATT),
+            PowerUnit::Megawatts | PowerUnit::MW => Power::Megawatts(base / Power::WATTS_PER_MEGAWATT),
+            PowerUnit::Gigawatts | PowerUnit::GW => Power::Gigawatts(base / Power::WATTS_PER_GIGAWATT),
+            PowerUnit::Horsepower | PowerUnit::hp => Power::Horsepower(base / Power::WATTS_PER_HORSEPOWER),
+            PowerUnit::MetricHorsepower | PowerUnit::PS => Power::MetricHorsepower(base / Power::WATTS_PER_METRIC_HORSEPOWER),
+            PowerUnit::BtuPerHour | PowerUnit::BTU_per_h => Power::BtuPerHour(base / Power::WATTS_PER_BTU_PER_HOUR),
+            PowerUnit::ErgsPerSecond | PowerUnit::erg_per_s => Power::ErgsPerSecond(base / Power::WATTS_PER_ERG_PER_SECOND),
+        }
+    }
+}
+
+
+impl Mul<f64> for Power {
+    type Output = Power;
+
+    fn mul(self, other: f64) -> Power {
+        match self {
+            Power::Watts(x) => Power::Watts(x * other),
+            Power::Kilowatts(x) => Power::Kilowatts(x * other),
+            Power::Megawatts(x) => Power::Megawatts(x * other),
+            Power::Gigawatts(x) => Power::Gigawatts(x * other),
+            Power::Horsepower(x) => Power::Horsepower(x * other),
+            Power::MetricHorsepower(x) => Power::MetricHorsepower(x * other),
+            Power::BtuPerHour(x) => Power::BtuPerHour(x * other),
+            Power::ErgsPerSecond(x) => Power::ErgsPerSecond(x * other),
+        }
+    }
+}
+
+
+impl Mul<Power> for f64 {
+    type Output = Power;
+
+    fn mul(self, other: Power) -> Power {
+        match other {
+            Power::Watts(x) => Power::Watts(x * self),
+            Power::Kilowatts(x) => Power::Kilowatts(x * self),
+            Power::Megawatts(x) => Power::Megawatts(x * self),
+            Power::Gigawatts(x) => Power::Gigawatts(x * self),
+            Power::Horsepower(x) => Power::Horsepower(x * self),
+            Power::MetricHorsepower(x) => Power::MetricHorsepower(x * self),
+            Power::BtuPerHour(x) => Power::BtuPerHour(x * self),
+            Power::ErgsPerSecond(x) => Power::ErgsPerSecond(x * self),
+        }
+    }
+}
+
+
+impl Div<f64> for Power {
+    type Output = Power;
+
+    fn div(self, other: f64) -> Power {
+        match self {
+            Power::Watts(x) => Power::Watts(x / other),
+            Power::Kilowatts(x) => Power::Kilowatts(x / other),
+            Power::Megawatts(x) => Power::Megawatts(x / other),
+            Power::Gigawatts(x) => Power::Gigawatts(x / other),
+            Power::Horsepower(x) => Power::Horsepower(x / other),
+            Power::MetricHorsepower(x) => Power::MetricHorsepower(x / other),
+            Power::BtuPerHour(x) => Power::BtuPerHour(x / other),
+            Power::ErgsPerSecond(x) => Power::ErgsPerSecond(x / other),
+        }
+    }
+}
+
+
+impl Display for Power {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (x, symbol, name) = match self {
+            Power::Watts(x) => (x, "W", "watts"),
+            Power::Kilowatts(x) => (x, "kW", "kilowatts"),
+            Power::Megawatts(x) => (x, "MW", "megawatts"),
+            Power::Gigawatts(x) => (x, "GW", "gigawatts"),
+            Power::Horsepower(x) => (x, "hp", "horsepower"),
+            Power::MetricHorsepower(x) => (x, "PS", "metric horsepower"),
+            Power::BtuPerHour(x) => (x, "BTU/h", "BTU per hour"),
+            Power::ErgsPerSecond(x) => (x, "erg/s", "ergs per second"),
+        };
+        utils::fmt_quantity(f, *x, symbol, name)
+    }
+}
+
+
+impl Div<Time> for Energy {
+    type Output = Power;
+
+    /// Divides an energy by a time. Watt-hours, kilowatt-hours, BTUs and ergs produce watts,
+    /// kilowatts, BTU/h and erg/s respectively; everything else produces watts.
+    fn div(self, rhs: Time) -> Power {
+        let unit = match self {
+            Energy::KilowattHours(_) => PowerUnit::Kilowatts,
+            Energy::BritishThermalUnits(_) => PowerUnit::BtuPerHour,
+            Energy::Ergs(_) => PowerUnit::ErgsPerSecond,
+            _ => PowerUnit::Watts,
+        };
+        let power: Quantity<dimension::Power> = Quantity::from(self) / Quantity::from(rhs);
+        Power::from(power).to(unit)
+    }
+}
+
+
+impl Mul<Time> for Power {
+    type Output = Energy;
+
+    /// Multiplies a power by a time. Kilowatts and larger produce kilowatt-hours, BTU/h produces
+    /// BTUs and erg/s produces ergs; everything else produces joules.
+    fn mul(self, rhs: Time) -> Energy {
+        let unit = match self {
+            Power::Kilowatts(_) | Power::Megawatts(_) | Power::Gigawatts(_) => EnergyUnit::KilowattHours,
+            Power::BtuPerHour(_) => EnergyUnit::BritishThermalUnits,
+            Power::ErgsPerSecond(_) => EnergyUnit::Ergs,
+            _ => EnergyUnit::Joules,
+        };
+        let energy: Quantity<dimension::Energy> = Quantity::from(self) * Quantity::from(rhs);
+        Energy::from(energy).to(unit)
+    }
+}
+
+
+impl Div<Power> for Energy {
+    type Output = Time;
+
+    /// Divides an energy by a power. Hour-based energies (watt-hours, kilowatt-hours and BTUs)
+    /// produce hours; everything else produces seconds.
+    fn div(self, rhs: Power) -> Time {
+        let time: Quantity<dimension::Time> = Quantity::from(self) / Quantity::from(rhs);
+        match self {
+            Energy::WattHours(_) | Energy::KilowattHours(_) | Energy::BritishThermalUnits(_) => {
+                Time::from(time).to(TimeUnit::Hours)
+            }
+            _ => Time::from(time),
+        }
+    }
+}
+
+
+/*--------------------( Unit Tests )--------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_base() {
+        match Power::Kilowatts(1.5).to_base() {
+            Power::Watts(x) => assert_eq!(x, 1500.0),
+            _ => panic!("expected Power::Watts variant"),
+        }
+        assert_eq!(Power::MetricHorsepower(2.0).to_base().value(), 1470.9975);
+    }
+
+    #[test]
+    fn test_from_base() {
+        match Power::Watts(2.5e6).to(PowerUnit::MW) {
+            Power::Megawatts(x) => assert_eq!(x, 2.5),
+            _ => panic!("expected Power::Megawatts variant"),
+        }
+        let hp = Power::Horsepower(1.0);
+        assert!((hp.to(PowerUnit::PS).value() - 1.013_869_665).abs() < 1e-9);
+        let ton = Power::BtuPerHour(12_000.0);
+        assert!((ton.to(PowerUnit::kW).value() - 3.516_852_842).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cmp() {
+        assert!(Power::Horsepower(1.0) > Power::MetricHorsepower(1.0));
+        assert!(Power::Gigawatts(1.0) > Power::Megawatts(999.0));
+        assert_eq!(Power::Kilowatts(1000.0), Power::Megawatts(1.0));
+        for x in [1.0, 2.5, 7.3] {
+            let power = Power::Gigawatts(x);
+            assert_eq!(power.to(PowerUnit::Horsepower), power);
+            assert_eq!(power.to(PowerUnit::BtuPerHour), power);
+        }
+    }
+
+    #[test]
+    fn test_add_sub() {
+        match Power::Kilowatts(1.0) + Power::Watts(500.0) {
+            Power::Kilowatts(x) => assert_eq!(x, 1.5),
+            _ => panic!("expected Power::Kilowatts variant"),
+        }
+        match Power::Megawatts(1.0) - Power::Kilowatts(250.0) {
+            Power::Megawatts(x) => assert_eq!(x, 0.75),
+            _ => panic!("expected Power::Megawatts variant"),
+        }
+    }
+
+    #[test]
+    fn test_scalar_ops() {
+        let power = Power::Watts(60.0);
+        assert_eq!(power * 2.0, Power::Watts(120.0));
+        assert_eq!(2.0 * power, Power::Watts(120.0));
+        assert_eq!(power / 4.0, Power::Watts(15.0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Power::Watts(60.0)), "60 W");
+        assert_eq!(format!("{}", Power::Kilowatts(2.5)), "2.5 kW");
+        assert_eq!(format!("{}", Power::BtuPerHour(12000.0)), "12000 BTU/h");
+    }
+
+    #[test]
+    fn test_energy_div_time() {
+        match Energy::KilowattHours(12.0) / Time::Hours(3.0) {
+            Power::Kilowatts(x) => assert_eq!(x, 4.0),
+            _ => panic!("expected Power::Kilowatts variant"),
+        }
+        match Energy::Joules(600.0) / Time::Minutes(1.0) {
+            Power::Watts(x) => assert_eq!(x, 10.0),
+            _ => panic!("expected Power::Watts variant"),
+        }
+        match Energy::BritishThermalUnits(24_000.0) / Time::Hours(2.0) {
+            Power::BtuPerHour(x) => assert_eq!(x, 12_000.0),
+            _ => panic!("expected Power::BtuPerHour variant"),
+        }
+    }
+
+    #[test]
+    fn test_power_times_time() {
+        match Power::Kilowatts(3.5) * Time::Hours(2.0) {
+            Energy::KilowattHours(x) => assert_eq!(x, 7.0),
+            _ => panic!("expected Energy::KilowattHours variant"),
+        }
+        match Power::Watts(100.0) * Time::Minutes(1.0) {
+            Energy::Joules(x) => assert_eq!(x, 6000.0),
+            _ => panic!("expected Energy::Joules variant"),
+        }
+    }
+
+    #[test]
+    fn test_energy_div_power() {
+        // Battery runtime: a 100 Wh pack driving a 20 W load
+        match Energy::WattHours(100.0) / Power::Watts(20.0) {
+            Time::Hours(x) => assert_eq!(x, 5.0),
+            _ => panic!("expected Time::Hours variant"),
+        }
+        match Energy::Kilojoules(3.0) / Power::Kilowatts(1.5) {
+            Time::Seconds(x) => assert_eq!(x, 2.0),
+            _ => panic!("expected Time::Seconds variant"),
+        }
+    }
+}
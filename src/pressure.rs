@@ -0,0 +1,310 @@
+//! This module contains the `Pressure` enum and its associated unit conversions.
+//! The `Pressure` enum is used to represent pressures in pascals, bars, atmospheres, etc.
+//! The `PressureUnit` enum is used to specify the unit of the pressure.
+//! The `Pressure` enum implements the `Unit` and `UnitConversion` traits, which offer the ability to 
+//! convert between different units of pressure via the `to_base` and `to` methods.
+
+use std::ops::{Mul, Div};
+use std::fmt::Display;
+use crate::unit_traits::UnitConversion;
+use crate::unit_traits::Unit;
+use crate::force::Force;
+use crate::area::Area;
+use crate::quantity::Quantity;
+use crate::dimension;
+use crate::utils;
+
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[allow(non_camel_case_types)]
+pub enum PressureUnit {
+    Pascals,
+    Pa,
+    Kilopascals,
+    kPa,
+    Bars,
+    bar,
+    Atmospheres,
+    atm,
+    PoundsPerSqInch,
+    psi,
+    MillimetersOfMercury,
+    mmHg,
+    Torr,
+    torr,
+}
+
+
+/// The `Pressure` enum represents pressures in pascals, bars, atmospheres, etc.
+#[derive(Debug, Clone, Copy)]
+pub enum Pressure {
+    Pascals(f64),
+    Kilopascals(f64),
+    Bars(f64),
+    Atmospheres(f64),
+    PoundsPerSqInch(f64),
+    MillimetersOfMercury(f64),
+    Torr(f64),
+}
+
+impl Pressure {
+    pub const PASCALS_PER_KILOPASCAL: f64 = 1000.0;
+    pub const PASCALS_PER_BAR: f64 = 100_000.0;
+    pub const PASCALS_PER_ATMOSPHERE: f64 = 101_325.0;
+    pub const PASCALS_PER_PSI: f64 = Force::NEWTONS_PER_POUND_FORCE / Area::SQ_METERS_PER_SQ_INCH;
+    pub const PASCALS_PER_MM_HG: f64 = 133.322_387_415;
+    pub const PASCALS_PER_TORR: f64 = 101_325.0 / 760.0;
+
+    /* ALIASES */
+
+    #[allow(non_snake_case)]
+    pub fn Pa(x: f64) -> Pressure {
+        Pressure::Pascals(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn kPa(x: f64) -> Pressure {
+        Pressure::Kilopascals(x)
+    }
+
+    pub fn bar(x: f64) -> Pressure {
+        Pressure::Bars(x)
+    }
+
+    pub fn atm(x: f64) -> Pressure {
+        Pressure::Atmospheres(x)
+    }
+
+    pub fn psi(x: f64) -> Pressure {
+        Pressure::PoundsPerSqInch(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn mmHg(x: f64) -> Pressure {
+        Pressure::MillimetersOfMercury(x)
+    }
+
+    pub fn torr(x: f64) -> Pressure {
+        Pressure::Torr(x)
+    }
+}
+
+impl Unit for Pressure {
+    /// Returns the inner f64 value of the Pressure enum.
+    fn value(&self) -> f64 {
+        match self {
+            Pressure::Pascals(x) => *x,
+            Pressure::Kilopascals(x) => *x,
+            Pressure::Bars(x) => *x,
+            Pressure::Atmospheres(x) => *x,
+            Pressure::PoundsPerSqInch(x) => *x,
+            Pressure::MillimetersOfMercury(x) => *x,
+            Pressure::Torr(x) => *x,
+        }
+    }
+}
+
+impl UnitConversion for Pressure {
+    type Units = PressureUnit;
+
+    /// Returns the unit the Pressure is expressed in.
+    fn unit(&self) -> PressureUnit {
+        match self {
+            Pressure::Pascals(_) => PressureUnit::Pascals,
+            Pressure::Kilopascals(_) => PressureUnit::Kilopascals,
+            Pressure::Bars(_) => PressureUnit::Bars,
+            Pressure::Atmospheres(_) => PressureUnit::Atmospheres,
+            Pressure::PoundsPerSqInch(_) => PressureUnit::PoundsPerSqInch,
+            Pressure::MillimetersOfMercury(_) => PressureUnit::MillimetersOfMercury,
+            Pressure::Torr(_) => PressureUnit::Torr,
+        }
+    }
+
+    /// Converts a given instance of a Pressure enum into the base variant, `Pressure::Pascals`.
+    fn to_base(&self) -> Pressure {
+        match self {
+            Pressure::Pascals(x) => Pressure::Pascals(*x),
+            Pressure::Kilopascals(x) => Pressure::Pascals(x * Pressure::PASCALS_PER_KILOPASCAL),
+            Pressure::Bars(x) => Pressure::Pascals(x * Pressure::PASCALS_PER_BAR),
+            Pressure::Atmospheres(x) => Pressure::Pascals(x * Pressure::PASCALS_PER_ATMOSPHERE),
+            Pressure::PoundsPerSqInch(x) => Pressure::Pascals(x * Pressure::PASCALS_PER_PSI),
+            Pressure::MillimetersOfMercury(x) => Pressure::Pascals(x * Pressure::PASCALS_PER_MM_HG),
+            Pressure::Torr(x) => Pressure::Pascals(x * Pressure::PASCALS_PER_TORR),
+        }
+    }
+
+    /// Converts a given instance of a Pressure enum into the specified variant as indicated by
+    /// the `PressureUnit` provided.
+    fn to(&self, variant: PressureUnit) -> Pressure {
+        let base = self.to_base().value();
+        match variant {
+            PressureUnit::Pascals | PressureUnit::Pa => Pressure::Pascals(base),
+            PressureUnit::Kilopascals | PressureUnit::kPa => Pressure::Kilopascals(base / Pressure::PASCALS_PER_KILOPASCAL),
+            PressureUnit::Bars | PressureUnit::bar => Pressure::Bars(base / Pressure::PASCALS_PER_BAR),
+            PressureUnit::Atmospheres | PressureUnit::atm => Pressure::Atmospheres(base / Pressure::PASCALS_PER_ATMOSPHERE),
+            PressureUnit::PoundsPerSqInch | PressureUnit::psi => Pressure::PoundsPerSqInch(base / Pressure::PASCALS_PER_PSI),
+            PressureUnit::MillimetersOfMercury | PressureUnit::mmHg => Pressure::MillimetersOfMercury(base / Pressure::PASCALS_PER_MM_HG),
+            PressureUnit::Torr | PressureUnit::torr => Pressure::Torr(base / Pressure::PASCALS_PER_TORR),
+        }
+    }
+}
+
+
+impl Mul<f64> for Pressure {
+    type Output = Pressure;
+
+    fn mul(self, other: f64) -> Pressure {
+        match self {
+            Pressure::Pascals(x) => Pressure::Pascals(x * other),
+            Pressure::Kilopascals(x) => Pressure::Kilopascals(x * other),
+            Pressure::Bars(x) => Pressure::Bars(x * other),
+            Pressure::Atmospheres(x) => Pressure::Atmospheres(x * other),
+            Pressure::PoundsPerSqInch(x) => Pressure::PoundsPerSqInch(x * other),
+            Pressure::MillimetersOfMercury(x) => Pressure::MillimetersOfMercury(x * other),
+            Pressure::Torr(x) => Pressure::Torr(x * other),
+        }
+    }
+}
+
+
+impl Mul<Pressure> for f64 {
+    type Output = Pressure;
+
+    fn mul(self, other: Pressure) -> Pressure {
+        match other {
+            Pressure::Pascals(x) => Pressure::Pascals(x * self),
+            Pressure::Kilopascals(x) => Pressure::Kilopascals(x * self),
+            Pressure::Bars(x) => Pressure::Bars(x * self),
+            Pressure::Atmospheres(x) => Pressure::Atmospheres(x * self),
+            Pressure::PoundsPerSqInch(x) => Pressure::PoundsPerSqInch(x * self),
+            Pressure::MillimetersOfMercury(x) => Pressure::MillimetersOfMercury(x * self),
+            Pressure::Torr(x) => Pressure::Torr(x * self),
+        }
+    }
+}
+
+
+impl Div<f64> for Pressure {
+    type Output = Pressure;
+
+    fn div(self, other: f64) -> Pressure {
+        match self {
+            Pressure::Pascals(x) => Pressure::Pascals(x / other),
+            Pressure::Kilopascals(x) => Pressure::Kilopascals(x / other),
+            Pressure::Bars(x) => Pressure::Bars(x / other),
+            Pressure::Atmospheres(x) => Pressure::Atmospheres(x / other),
+            Pressure::PoundsPerSqInch(x) => Pressure::PoundsPerSqInch(x / other),
+            Pressure::MillimetersOfMercury(x) => Pressure::MillimetersOfMercury(x / other),
+            Pressure::Torr(x) => Pressure::Torr(x / other),
+        }
+    }
+}
+
+
+impl Display for Pressure {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (x, symbol, name) = match self {
+            Pressure::Pascals(x) => (x, "Pa", "pascals"),
+            Pressure::Kilopascals(x) => (x, "kPa", "kilopascals"),
+            Pressure::Bars(x) => (x, "bar", "bars"),
+            Pressure::Atmospheres(x) => (x, "atm", "atmospheres"),
+            Pressure::PoundsPerSqInch(x) => (x, "psi", "pounds per square inch"),
+            Pressure::MillimetersOfMercury(x) => (x, "mmHg", "millimeters of mercury"),
+            Pressure::Torr(x) => (x, "Torr", "torr"),
+        };
+        utils::fmt_quantity(f, *x, symbol, name)
+    }
+}
+
+
+impl Div<Area> for Force {
+    type Output = Pressure;
+
+    /// Divides a force by an area. Pounds-force produce pounds per square inch; everything else
+    /// produces pascals.
+    fn div(self, rhs: Area) -> Pressure {
+        let pressure: Quantity<dimension::Pressure> = Quantity::from(self) / Quantity::from(rhs);
+        match self {
+            Force::PoundsForce(_) => Pressure::from(pressure).to(PressureUnit::PoundsPerSqInch),
+            _ => Pressure::from(pressure),
+        }
+    }
+}
+
+
+/*--------------------( Unit Tests )--------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_base() {
+        match Pressure::Bars(1.5).to_base() {
+            Pressure::Pascals(x) => assert_eq!(x, 150_000.0),
+            _ => panic!("expected Pressure::Pascals variant"),
+        }
+        assert_eq!(Pressure::Atmospheres(1.0).to_base().value(), 101_325.0);
+    }
+
+    #[test]
+    fn test_from_base() {
+        match Pressure::Pascals(101_325.0).to(PressureUnit::torr) {
+            Pressure::Torr(x) => assert!((x - 760.0).abs() < 1e-9),
+            _ => panic!("expected Pressure::Torr variant"),
+        }
+        let atm = Pressure::Atmospheres(1.0);
+        assert!((atm.to(PressureUnit::psi).value() - 14.695_948_775).abs() < 1e-8);
+        assert!((atm.to(PressureUnit::mmHg).value() - 760.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_cmp() {
+        assert!(Pressure::Atmospheres(1.0) > Pressure::Bars(1.0));
+        assert!(Pressure::PoundsPerSqInch(15.0) > Pressure::Atmospheres(1.0));
+        assert_eq!(Pressure::Kilopascals(100.0), Pressure::Bars(1.0));
+        assert_ne!(Pressure::Pascals(1.0e-17), Pressure::Pascals(2.0e-17));
+        assert!(Pressure::Torr(1.0e-15) < Pressure::Torr(1.0e-14));
+    }
+
+    #[test]
+    fn test_add_sub() {
+        match Pressure::Bars(1.0) + Pressure::Kilopascals(50.0) {
+            Pressure::Bars(x) => assert_eq!(x, 1.5),
+            _ => panic!("expected Pressure::Bars variant"),
+        }
+        match Pressure::Kilopascals(200.0) - Pressure::Bars(1.0) {
+            Pressure::Kilopascals(x) => assert_eq!(x, 100.0),
+            _ => panic!("expected Pressure::Kilopascals variant"),
+        }
+    }
+
+    #[test]
+    fn test_scalar_ops() {
+        let pressure = Pressure::PoundsPerSqInch(32.0);
+        assert_eq!(pressure * 2.0, Pressure::PoundsPerSqInch(64.0));
+        assert_eq!(2.0 * pressure, Pressure::PoundsPerSqInch(64.0));
+        assert_eq!(pressure / 4.0, Pressure::PoundsPerSqInch(8.0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Pressure::Pascals(12.5)), "12.5 Pa");
+        assert_eq!(format!("{}", Pressure::PoundsPerSqInch(32.0)), "32 psi");
+        assert_eq!(format!("{}", Pressure::MillimetersOfMercury(760.0)), "760 mmHg");
+    }
+
+    #[test]
+    fn test_force_div_area() {
+        match Force::Newtons(500.0) / Area::SqMeters(2.0) {
+            Pressure::Pascals(x) => assert_eq!(x, 250.0),
+            _ => panic!("expected Pressure::Pascals variant"),
+        }
+        match Force::PoundsForce(100.0) / Area::SqInches(4.0) {
+            Pressure::PoundsPerSqInch(x) => assert_eq!(x, 25.0),
+            _ => panic!("expected Pressure::PoundsPerSqInch variant"),
+        }
+        let tire = Force::PoundsForce(144.0) / Area::SqFeet(1.0);
+        assert!((tire.value() - 1.0).abs() < 1e-12);
+    }
+}
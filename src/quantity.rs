@@ -0,0 +1,437 @@
+//! This module contains the generic `Quantity` type, a value in SI base units whose dimension is
+//! checked at compile time.
+//!
+//! The dimension `D` is one of the types in the `dimension` module. Multiplying or dividing two
+//! quantities works out the dimension of the result, so a length times a length is a
+//! `Quantity<dimension::Area>` without a dedicated impl, and adding quantities of different
+//! dimensions does not compile. Each unit type converts to and from the quantity of its
+//! dimension with `From`.
+//!
+//! The unit enums are built on `Quantity`: comparing, adding or subtracting two values, and
+//! products and quotients across unit types such as `Length * Length` or `Data / Time`, convert
+//! the operands into quantities, do the arithmetic there and convert the result back. The enum
+//! only chooses the unit the result is expressed in. Scaling a value by an `f64` stays in the
+//! value's own unit, since it cannot change the dimension.
+//!
+//! Values are stored in meters, kilograms, seconds, kelvin, bytes and radians. Bytes rather than
+//! bits are the base of information to match `Data`, so a `DataRate` is held in bytes per
+//! second. Angles have a dimension of their own, so an `AngularSpeed` cannot be converted into a
+//! `Frequency` by way of a `Quantity`.
+//!
+//! Only a `TemperatureDelta` converts into a `Quantity`. An absolute `Temperature` has no
+//! quantity, since adding two temperatures is meaningless; subtract them to get a delta first.
+//!
+//! ```
+//! use unit_types::{Length, Time, Speed, Quantity};
+//! use unit_types::dimension;
+//! use unit_types::prelude::*;
+//!
+//! let width = Quantity::from(Length::Meters(3.0));
+//! let area: Quantity<dimension::Area> = width * width;
+//! assert_eq!(area.value(), 9.0);
+//!
+//! let speed = Quantity::from(Length::Kilometers(1.0)) / Quantity::from(Time::Seconds(4.0));
+//! assert_eq!(Speed::from(speed), Speed::MetersPerSecond(250.0));
+//! ```
+//!
+//! Quantities of different dimensions cannot be added:
+//!
+//! ```compile_fail
+//! use unit_types::{Length, Time, Quantity};
+//! let _ = Quantity::from(Length::Meters(1.0)) + Quantity::from(Time::Seconds(1.0));
+//! ```
+//!
+//! Nor can an angular speed be read back as a frequency:
+//!
+//! ```compile_fail
+//! use unit_types::{AngularSpeed, Frequency, Quantity};
+//! let _ = Frequency::from(Quantity::from(AngularSpeed::RadiansPerSecond(1.0)));
+//! ```
+//!
+//! Or an absolute temperature be turned into one:
+//!
+//! ```compile_fail
+//! use unit_types::{Temperature, Quantity};
+//! let _ = Quantity::from(Temperature::Celsius(20.0)) + Quantity::from(Temperature::Celsius(30.0));
+//! ```
+
+use std::marker::PhantomData;
+use std::ops::{Add, Sub, Mul, Div, Neg};
+use std::fmt::Display;
+use crate::dimension::{self, Dimension, DimMul, DimDiv, Dimensionless};
+use crate::unit_traits::UnitConversion;
+use crate::unit_traits::Unit;
+use crate::utils;
+use crate::{
+    Length, Time, Data, DataRate, Area, Volume, Speed, Acceleration, Mass, Force, Pressure, Energy,
+    Power, TemperatureDelta, Frequency, Angle, AngularSpeed, AngularAcceleration,
+};
+
+
+/// The symbols of the base units, in the order of `Dimension::EXPONENTS`.
+const BASE_SYMBOLS: [&str; 6] = ["m", "kg", "s", "K", "B", "rad"];
+
+
+/// A value of dimension `D`, expressed in SI base units.
+pub struct Quantity<D> {
+    value: f64,
+    dimension: PhantomData<D>,
+}
+
+impl<D> Quantity<D> {
+    /// Creates a quantity from a value in the base units of its dimension.
+    pub fn new(value: f64) -> Quantity<D> {
+        Quantity { value, dimension: PhantomData }
+    }
+}
+
+impl<D> Unit for Quantity<D> {
+    /// Returns the value in the base units of the quantity's dimension.
+    fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl<D> Clone for Quantity<D> {
+    fn clone(&self) -> Quantity<D> {
+        *self
+    }
+}
+
+impl<D> Copy for Quantity<D> {}
+
+impl<D: Dimension> std::fmt::Debug for Quantity<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Quantity")
+            .field("value", &self.value)
+            .field("exponents", &D::EXPONENTS)
+            .finish()
+    }
+}
+
+impl<D: Dimension> Display for Quantity<D> {
+    /// Writes the value followed by its base units, e.g. `9.81 m s^-2`, or the value alone when
+    /// the quantity is dimensionless.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let units: Vec<String> = D::EXPONENTS
+            .iter()
+            .zip(BASE_SYMBOLS)
+            .filter(|(exponent, _)| **exponent != 0)
+            .map(|(exponent, symbol)| match exponent {
+                1 => symbol.to_string(),
+                _ => format!("{}^{}", symbol, exponent),
+            })
+            .collect();
+        if units.is_empty() {
+            return Display::fmt(&self.value, f);
+        }
+        let units = units.join(" ");
+        utils::fmt_quantity(f, self.value, &units, &units)
+    }
+}
+
+impl<D> PartialEq for Quantity<D> {
+    fn eq(&self, other: &Quantity<D>) -> bool {
+        utils::approx_eq(self.value, other.value)
+    }
+}
+
+impl<D> PartialOrd for Quantity<D> {
+    fn partial_cmp(&self, other: &Quantity<D>) -> Option<std::cmp::Ordering> {
+        utils::approx_cmp(self.value, other.value)
+    }
+}
+
+
+impl<D> Add for Quantity<D> {
+    type Output = Quantity<D>;
+
+    fn add(self, other: Quantity<D>) -> Quantity<D> {
+        Quantity::new(self.value + other.value)
+    }
+}
+
+impl<D> Sub for Quantity<D> {
+    type Output = Quantity<D>;
+
+    fn sub(self, other: Quantity<D>) -> Quantity<D> {
+        Quantity::new(self.value - other.value)
+    }
+}
+
+impl<D> Neg for Quantity<D> {
+    type Output = Quantity<D>;
+
+    fn neg(self) -> Quantity<D> {
+        Quantity::new(-self.value)
+    }
+}
+
+impl<D1: DimMul<D2>, D2> Mul<Quantity<D2>> for Quantity<D1> {
+    type Output = Quantity<<D1 as DimMul<D2>>::Output>;
+
+    fn mul(self, other: Quantity<D2>) -> Self::Output {
+        Quantity::new(self.value * other.value)
+    }
+}
+
+impl<D1: DimDiv<D2>, D2> Div<Quantity<D2>> for Quantity<D1> {
+    type Output = Quantity<<D1 as DimDiv<D2>>::Output>;
+
+    fn div(self, other: Quantity<D2>) -> Self::Output {
+        Quantity::new(self.value / other.value)
+    }
+}
+
+impl<D> Mul<f64> for Quantity<D> {
+    type Output = Quantity<D>;
+
+    fn mul(self, other: f64) -> Quantity<D> {
+        Quantity::new(self.value * other)
+    }
+}
+
+impl<D> Mul<Quantity<D>> for f64 {
+    type Output = Quantity<D>;
+
+    fn mul(self, other: Quantity<D>) -> Quantity<D> {
+        Quantity::new(self * other.value)
+    }
+}
+
+impl<D> Div<f64> for Quantity<D> {
+    type Output = Quantity<D>;
+
+    fn div(self, other: f64) -> Quantity<D> {
+        Quantity::new(self.value / other)
+    }
+}
+
+impl<D> Div<Quantity<D>> for f64
+where
+    Dimensionless: DimDiv<D>,
+{
+    type Output = Quantity<<Dimensionless as DimDiv<D>>::Output>;
+
+    fn div(self, other: Quantity<D>) -> Self::Output {
+        Quantity::new(self / other.value)
+    }
+}
+
+
+/// Implements `From` in both directions between a unit type and the quantity of its dimension.
+/// `per_base` is the number of the unit type's base unit in one SI base unit.
+macro_rules! quantity_conversions {
+    ($unit:ident, $dimension:ty, $base:path, $per_base:expr) => {
+        impl From<$unit> for Quantity<$dimension> {
+            fn from(x: $unit) -> Quantity<$dimension> {
+                Quantity::new(x.to_base().value() / $per_base)
+            }
+        }
+
+        impl From<Quantity<$dimension>> for $unit {
+            /// Converts the quantity into the unit type's base unit.
+            fn from(x: Quantity<$dimension>) -> $unit {
+                $base(x.value * $per_base)
+            }
+        }
+    };
+}
+
+quantity_conversions!(Length, dimension::Length, Length::Meters, 1.0);
+quantity_conversions!(Time, dimension::Time, Time::Seconds, 1.0);
+quantity_conversions!(Data, dimension::Data, Data::Bytes, 1.0);
+quantity_conversions!(DataRate, dimension::DataRate, DataRate::bps, DataRate::BITS_PER_BYTE);
+quantity_conversions!(Area, dimension::Area, Area::SqMeters, 1.0);
+quantity_conversions!(Volume, dimension::Volume, Volume::CubicMeters, 1.0);
+quantity_conversions!(Speed, dimension::Speed, Speed::MetersPerSecond, 1.0);
+quantity_conversions!(Acceleration, dimension::Acceleration, Acceleration::MetersPerSecondSquared, 1.0);
+quantity_conversions!(Mass, dimension::Mass, Mass::Kilograms, 1.0);
+quantity_conversions!(Force, dimension::Force, Force::Newtons, 1.0);
+quantity_conversions!(Pressure, dimension::Pressure, Pressure::Pascals, 1.0);
+quantity_conversions!(Energy, dimension::Energy, Energy::Joules, 1.0);
+quantity_conversions!(Power, dimension::Power, Power::Watts, 1.0);
+quantity_conversions!(TemperatureDelta, dimension::Temperature, TemperatureDelta::Kelvin, 1.0);
+quantity_conversions!(Frequency, dimension::Frequency, Frequency::Hertz, 1.0);
+quantity_conversions!(Angle, dimension::Angle, Angle::Radians, 1.0);
+quantity_conversions!(AngularSpeed, dimension::AngularSpeed, AngularSpeed::RadiansPerSecond, 1.0);
+quantity_conversions!(AngularAcceleration, dimension::AngularAcceleration, AngularAcceleration::RadiansPerSecondSquared, 1.0);
+
+/// Implements comparison, addition and subtraction for a unit type by converting both operands
+/// into the quantity of its dimension, so every unit type follows the same rules. Sums and
+/// differences are expressed in the unit of the left operand.
+macro_rules! quantity_arithmetic {
+    ($unit:ident, $dimension:ty) => {
+        impl PartialEq for $unit {
+            fn eq(&self, other: &$unit) -> bool {
+                Quantity::<$dimension>::from(*self) == Quantity::from(*other)
+            }
+        }
+
+        impl PartialOrd for $unit {
+            fn partial_cmp(&self, other: &$unit) -> Option<std::cmp::Ordering> {
+                Quantity::<$dimension>::from(*self).partial_cmp(&Quantity::from(*other))
+            }
+        }
+
+        impl Add for $unit {
+            type Output = $unit;
+
+            fn add(self, other: $unit) -> $unit {
+                $unit::from(Quantity::<$dimension>::from(self) + Quantity::from(other)).to(self.unit())
+            }
+        }
+
+        impl Sub for $unit {
+            type Output = $unit;
+
+            fn sub(self, other: $unit) -> $unit {
+                $unit::from(Quantity::<$dimension>::from(self) - Quantity::from(other)).to(self.unit())
+            }
+        }
+    };
+}
+
+quantity_arithmetic!(Length, dimension::Length);
+quantity_arithmetic!(Time, dimension::Time);
+quantity_arithmetic!(Data, dimension::Data);
+quantity_arithmetic!(DataRate, dimension::DataRate);
+quantity_arithmetic!(Area, dimension::Area);
+quantity_arithmetic!(Volume, dimension::Volume);
+quantity_arithmetic!(Speed, dimension::Speed);
+quantity_arithmetic!(Acceleration, dimension::Acceleration);
+quantity_arithmetic!(Mass, dimension::Mass);
+quantity_arithmetic!(Force, dimension::Force);
+quantity_arithmetic!(Pressure, dimension::Pressure);
+quantity_arithmetic!(Energy, dimension::Energy);
+quantity_arithmetic!(Power, dimension::Power);
+quantity_arithmetic!(TemperatureDelta, dimension::Temperature);
+quantity_arithmetic!(Frequency, dimension::Frequency);
+quantity_arithmetic!(Angle, dimension::Angle);
+quantity_arithmetic!(AngularSpeed, dimension::AngularSpeed);
+quantity_arithmetic!(AngularAcceleration, dimension::AngularAcceleration);
+
+
+/*--------------------( Unit Tests )--------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::assert_close;
+    use crate::length::LengthUnit;
+    use crate::data_rate::DataRateUnit;
+    use crate::speed::SpeedUnit;
+
+    #[test]
+    fn test_area_from_lengths() {
+        let a = Quantity::from(Length::Feet(10.0));
+        let b = Quantity::from(Length::Meters(2.0));
+        let area: Quantity<dimension::Area> = a * b;
+        assert_close(area.value(), 6.096);
+        assert_eq!(Area::from(area), Area::SqMeters(6.096));
+    }
+
+    #[test]
+    fn test_data_rate_from_data_and_time() {
+        let data = Quantity::from(Data::MB(10.0));
+        let time = Quantity::from(Time::Seconds(2.0));
+        let rate = DataRate::from(data / time);
+        assert_close(rate.to(DataRateUnit::MBps).value(), 5.0);
+        assert_close(rate.to(DataRateUnit::Mbps).value(), 40.0);
+
+        let back: Quantity<dimension::Data> = Quantity::from(rate) * time;
+        assert_close(Data::from(back).value(), 1.0e7);
+    }
+
+    #[test]
+    fn test_derived_dimensions() {
+        let mass = Quantity::from(Mass::Kilograms(2.0));
+        let acceleration = Quantity::from(Acceleration::StandardGravity(1.0));
+        let force: Quantity<dimension::Force> = mass * acceleration;
+        assert_close(force.value(), 2.0 * 9.80665);
+
+        let energy: Quantity<dimension::Energy> = force * Quantity::from(Length::Meters(3.0));
+        let power: Quantity<dimension::Power> = energy / Quantity::from(Time::Minutes(1.0));
+        assert_close(Power::from(power).value(), 2.0 * 9.80665 * 3.0 / 60.0);
+
+        let pressure: Quantity<dimension::Pressure> = force / Quantity::from(Area::SqCentimeters(1.0));
+        assert_close(pressure.value(), 2.0 * 9.80665 * 1.0e4);
+
+        let frequency: Quantity<dimension::Frequency> = 1.0 / Quantity::from(Time::Milliseconds(20.0));
+        assert_close(Frequency::from(frequency).value(), 50.0);
+
+        let ratio: Quantity<dimension::Dimensionless> = Quantity::from(Length::Kilometers(1.0)) / Quantity::from(Length::Meters(250.0));
+        assert_close(ratio.value(), 4.0);
+    }
+
+    #[test]
+    fn test_unit_selection_round_trip() {
+        let speed = Quantity::from(Length::Miles(60.0)) / Quantity::from(Time::Hours(1.0));
+        match Speed::from(speed).to(SpeedUnit::MilesPerHour) {
+            Speed::MilesPerHour(x) => assert_close(x, 60.0),
+            _ => panic!("expected Speed::MilesPerHour variant"),
+        }
+        match Length::from(Quantity::from(Length::Feet(3.0))).to(LengthUnit::Yards) {
+            Length::Yards(x) => assert_close(x, 1.0),
+            _ => panic!("expected Length::Yards variant"),
+        }
+        let rise = Quantity::from(TemperatureDelta::Fahrenheit(18.0));
+        assert_close(rise.value(), 10.0);
+        assert_eq!(TemperatureDelta::from(rise), TemperatureDelta::Celsius(10.0));
+    }
+
+    #[test]
+    fn test_unit_type_ops_match_quantities() {
+        let width = Length::Feet(10.0);
+        let depth = Length::Meters(3.0);
+        let floor = width * depth;
+        assert_eq!(Quantity::from(floor), Quantity::from(width) * Quantity::from(depth));
+        assert!(matches!(floor, Area::SqFeet(_)));
+
+        let download = Data::Megabytes(300.0) / Time::Minutes(1.0);
+        assert_eq!(Quantity::from(download), Quantity::from(Data::Megabytes(300.0)) / Quantity::from(Time::Minutes(1.0)));
+        assert!(matches!(download, DataRate::MBps(_)));
+
+        let total = Length::Feet(1.0) + Length::Inches(6.0);
+        assert_eq!(Quantity::from(total), Quantity::from(Length::Feet(1.0)) + Quantity::from(Length::Inches(6.0)));
+        assert!(matches!(total, Length::Feet(_)));
+        assert!(Length::Inches(13.0) > Length::Feet(1.0));
+    }
+
+    #[test]
+    fn test_ops() {
+        let a = Quantity::<dimension::Length>::new(3.0);
+        let b = Quantity::<dimension::Length>::new(1.5);
+        assert_eq!(a + b, Quantity::new(4.5));
+        assert_eq!(a - b, Quantity::new(1.5));
+        assert_eq!(-a, Quantity::new(-3.0));
+        assert_eq!(a * 2.0, Quantity::new(6.0));
+        assert_eq!(2.0 * a, Quantity::new(6.0));
+        assert_eq!(a / 2.0, Quantity::new(1.5));
+        assert!(b < a);
+
+        let tiny = Quantity::<dimension::Length>::new(1.0e-17);
+        assert_ne!(tiny, tiny * 2.0);
+        assert!(tiny < tiny * 2.0);
+        assert_eq!(Quantity::<dimension::Length>::new(0.1) + Quantity::new(0.2), Quantity::new(0.3));
+    }
+
+    #[test]
+    fn test_angles() {
+        let angle = Quantity::from(Angle::Degrees(180.0));
+        let speed: Quantity<dimension::AngularSpeed> = angle / Quantity::from(Time::Seconds(2.0));
+        assert_close(AngularSpeed::from(speed).value(), std::f64::consts::FRAC_PI_2);
+        let acceleration: Quantity<dimension::AngularAcceleration> = speed / Quantity::from(Time::Seconds(1.0));
+        assert_close(AngularAcceleration::from(acceleration).value(), std::f64::consts::FRAC_PI_2);
+    }
+
+    #[test]
+    fn test_display() {
+        let force = Quantity::<dimension::Force>::new(9.81);
+        assert_eq!(format!("{}", force), "9.81 m kg s^-2");
+        assert_eq!(format!("{:.1}", Quantity::<dimension::DataRate>::new(2.0)), "2.0 s^-1 B");
+        assert_eq!(format!("{:.2}", Quantity::<dimension::Dimensionless>::new(1.0)), "1.00");
+        assert_eq!(format!("{}", Quantity::<dimension::AngularSpeed>::new(2.0)), "2 s^-1 rad");
+        assert_eq!(format!("{:?}", Quantity::<dimension::Speed>::new(2.0)), "Quantity { value: 2.0, exponents: [1, 0, -1, 0, 0, 0] }");
+    }
+}
@@ -0,0 +1,348 @@
+//! This module contains the `Speed` enum and its associated unit conversions.
+//! The `Speed` enum is used to represent speeds in meters per second, kilometers per hour, knots, etc.
+//! The `SpeedUnit` enum is used to specify the unit of the speed.
+//! The `Speed` enum implements the `Unit` and `UnitConversion` traits, which offer the ability to 
+//! convert between different units of speed via the `to_base` and `to` methods.
+
+use std::ops::{Mul, Div};
+use std::fmt::Display;
+use crate::unit_traits::UnitConversion;
+use crate::unit_traits::Unit;
+use crate::length::{Length, LengthUnit};
+use crate::time::Time;
+use crate::quantity::Quantity;
+use crate::dimension;
+use crate::utils;
+
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[allow(non_camel_case_types)]
+pub enum SpeedUnit {
+    MetersPerSecond,
+    mps,
+    KilometersPerHour,
+    kph,
+    MilesPerHour,
+    mph,
+    Knots,
+    kn,
+    FeetPerSecond,
+    fps,
+    Mach,
+}
+
+
+/// The `Speed` enum represents speeds in meters per second, kilometers per hour, knots, etc.
+#[derive(Debug, Clone, Copy)]
+pub enum Speed {
+    MetersPerSecond(f64),
+    KilometersPerHour(f64),
+    MilesPerHour(f64),
+    Knots(f64),
+    FeetPerSecond(f64),
+    Mach(f64),
+}
+
+impl Speed {
+    pub const METERS_PER_SECOND_PER_KPH: f64 = 1000.0 / 3600.0;
+    pub const METERS_PER_SECOND_PER_MPH: f64 = 1_609.344 / 3600.0;
+    pub const METERS_PER_SECOND_PER_KNOT: f64 = 1_852.0 / 3600.0;
+    pub const METERS_PER_SECOND_PER_FPS: f64 = 0.3048;
+    /// Speed of sound in dry air at sea level in the International Standard Atmosphere (15 °C).
+    pub const METERS_PER_SECOND_PER_MACH: f64 = 340.294;
+
+    /* ALIASES */
+
+    pub fn mps(x: f64) -> Speed {
+        Speed::MetersPerSecond(x)
+    }
+
+    pub fn kph(x: f64) -> Speed {
+        Speed::KilometersPerHour(x)
+    }
+
+    pub fn mph(x: f64) -> Speed {
+        Speed::MilesPerHour(x)
+    }
+
+    pub fn kn(x: f64) -> Speed {
+        Speed::Knots(x)
+    }
+
+    pub fn fps(x: f64) -> Speed {
+        Speed::FeetPerSecond(x)
+    }
+}
+
+impl Unit for Speed {
+    /// Returns the inner f64 value of the Speed enum.
+    fn value(&self) -> f64 {
+        match self {
+            Speed::MetersPerSecond(x) => *x,
+            Speed::KilometersPerHour(x) => *x,
+            Speed::MilesPerHour(x) => *x,
+            Speed::Knots(x) => *x,
+            Speed::FeetPerSecond(x) => *x,
+            Speed::Mach(x) => *x,
+        }
+    }
+}
+
+impl UnitConversion for Speed {
+    type Units = SpeedUnit;
+
+    /// Returns the unit the Speed is expressed in.
+    fn unit(&self) -> SpeedUnit {
+        match self {
+            Speed::MetersPerSecond(_) => SpeedUnit::MetersPerSecond,
+            Speed::KilometersPerHour(_) => SpeedUnit::KilometersPerHour,
+            Speed::MilesPerHour(_) => SpeedUnit::MilesPerHour,
+            Speed::Knots(_) => SpeedUnit::Knots,
+            Speed::FeetPerSecond(_) => SpeedUnit::FeetPerSecond,
+            Speed::Mach(_) => SpeedUnit::Mach,
+        }
+    }
+
+    /// Converts a given instance of a Speed enum into the base variant, `Speed::MetersPerSecond`.
+    fn to_base(&self) -> Speed {
+        match self {
+            Speed::MetersPerSecond(x) => Speed::MetersPerSecond(*x),
+            Speed::KilometersPerHour(x) => Speed::MetersPerSecond(x * Speed::METERS_PER_SECOND_PER_KPH),
+            Speed::MilesPerHour(x) => Speed::MetersPerSecond(x * Speed::METERS_PER_SECOND_PER_MPH),
+            Speed::Knots(x) => Speed::MetersPerSecond(x * Speed::METERS_PER_SECOND_PER_KNOT),
+            Speed::FeetPerSecond(x) => Speed::MetersPerSecond(x * Speed::METERS_PER_SECOND_PER_FPS),
+            Speed::Mach(x) => Speed::MetersPerSecond(x * Speed::METERS_PER_SECOND_PER_MACH),
+        }
+    }
+
+    /// Converts a given instance of a Speed enum into the specified variant as indicated by
+    /// the `SpeedUnit` provided.
+    fn to(&self, variant: SpeedUnit) -> Speed {
+        let base = self.to_base().value();
+        match variant {
+            SpeedUnit::MetersPerSecond | SpeedUnit::mps => Speed::MetersPerSecond(base),
+            SpeedUnit::KilometersPerHour | SpeedUnit::kph => Speed::KilometersPerHour(base / Speed::METERS_PER_SECOND_PER_KPH),
+            SpeedUnit::MilesPerHour | SpeedUnit::mph => Speed::MilesPerHour(base / Speed::METERS_PER_SECOND_PER_MPH),
+            SpeedUnit::Knots | SpeedUnit::kn => Speed::Knots(base / Speed::METERS_PER_SECOND_PER_KNOT),
+            SpeedUnit::FeetPerSecond | SpeedUnit::fps => Speed::FeetPerSecond(base / Speed::METERS_PER_SECOND_PER_FPS),
+            SpeedUnit::Mach => Speed::Mach(base / Speed::METERS_PER_SECOND_PER_MACH),
+        }
+    }
+}
+
+
+impl Mul<f64> for Speed {
+    type Output = Speed;
+
+    fn mul(self, other: f64) -> Speed {
+        match self {
+            Speed::MetersPerSecond(x) => Speed::MetersPerSecond(x * other),
+            Speed::KilometersPerHour(x) => Speed::KilometersPerHour(x * other),
+            Speed::MilesPerHour(x) => Speed::MilesPerHour(x * other),
+            Speed::Knots(x) => Speed::Knots(x * other),
+            Speed::FeetPerSecond(x) => Speed::FeetPerSecond(x * other),
+            Speed::Mach(x) => Speed::Mach(x * other),
+        }
+    }
+}
+
+
+impl Mul<Speed> for f64 {
+    type Output = Speed;
+
+    fn mul(self, other: Speed) -> Speed {
+        match other {
+            Speed::MetersPerSecond(x) => Speed::MetersPerSecond(x * self),
+            Speed::KilometersPerHour(x) => Speed::KilometersPerHour(x * self),
+            Speed::MilesPerHour(x) => Speed::MilesPerHour(x * self),
+            Speed::Knots(x) => Speed::Knots(x * self),
+            Speed::FeetPerSecond(x) => Speed::FeetPerSecond(x * self),
+            Speed::Mach(x) => Speed::Mach(x * self),
+        }
+    }
+}
+
+
+impl Div<f64> for Speed {
+    type Output = Speed;
+
+    fn div(self, other: f64) -> Speed {
+        match self {
+            Speed::MetersPerSecond(x) => Speed::MetersPerSecond(x / other),
+            Speed::KilometersPerHour(x) => Speed::KilometersPerHour(x / other),
+            Speed::MilesPerHour(x) => Speed::MilesPerHour(x / other),
+            Speed::Knots(x) => Speed::Knots(x / other),
+            Speed::FeetPerSecond(x) => Speed::FeetPerSecond(x / other),
+            Speed::Mach(x) => Speed::Mach(x / other),
+        }
+    }
+}
+
+
+impl Display for Speed {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (x, symbol, name) = match self {
+            Speed::MetersPerSecond(x) => (x, "m/s", "meters per second"),
+            Speed::KilometersPerHour(x) => (x, "km/h", "kilometers per hour"),
+            Speed::MilesPerHour(x) => (x, "mph", "miles per hour"),
+            Speed::Knots(x) => (x, "kn", "knots"),
+            Speed::FeetPerSecond(x) => (x, "ft/s", "feet per second"),
+            Speed::Mach(x) => (x, "Mach", "Mach"),
+        };
+        utils::fmt_quantity(f, *x, symbol, name)
+    }
+}
+
+
+impl Div<Time> for Length {
+    type Output = Speed;
+
+    /// Divides a length by a time. Meters and feet produce per-second speeds, while kilometers,
+    /// miles and nautical miles produce km/h, mph and knots. Other lengths produce meters per
+    /// second (metric) or feet per second (imperial).
+    fn div(self, rhs: Time) -> Speed {
+        let unit = match self {
+            Length::Millimeters(_) | Length::Centimeters(_) | Length::Meters(_) => SpeedUnit::MetersPerSecond,
+            Length::Kilometers(_) => SpeedUnit::KilometersPerHour,
+            Length::Miles(_) => SpeedUnit::MilesPerHour,
+            Length::NauticalMiles(_) => SpeedUnit::Knots,
+            Length::Inches(_) | Length::Feet(_) | Length::Yards(_) => SpeedUnit::FeetPerSecond,
+        };
+        let speed: Quantity<dimension::Speed> = Quantity::from(self) / Quantity::from(rhs);
+        Speed::from(speed).to(unit)
+    }
+}
+
+
+impl Mul<Time> for Speed {
+    type Output = Length;
+
+    /// Multiplies a speed by a time, producing the distance covered in the length unit matching
+    /// the speed's unit. Mach numbers produce meters.
+    fn mul(self, rhs: Time) -> Length {
+        let unit = match self {
+            Speed::MetersPerSecond(_) | Speed::Mach(_) => LengthUnit::Meters,
+            Speed::KilometersPerHour(_) => LengthUnit::Kilometers,
+            Speed::MilesPerHour(_) => LengthUnit::Miles,
+            Speed::Knots(_) => LengthUnit::NauticalMiles,
+            Speed::FeetPerSecond(_) => LengthUnit::Feet,
+        };
+        let length: Quantity<dimension::Length> = Quantity::from(self) * Quantity::from(rhs);
+        Length::from(length).to(unit)
+    }
+}
+
+
+impl Div<Speed> for Length {
+    type Output = Time;
+
+    /// Divides a length by a speed, producing the travel time in seconds.
+    fn div(self, rhs: Speed) -> Time {
+        let time: Quantity<dimension::Time> = Quantity::from(self) / Quantity::from(rhs);
+        Time::from(time)
+    }
+}
+
+
+/*--------------------( Unit Tests )--------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::TimeUnit;
+
+    #[test]
+    fn test_to_base() {
+        let speed = Speed::KilometersPerHour(36.0);
+        match speed.to_base() {
+            Speed::MetersPerSecond(x) => assert!((x - 10.0).abs() < 1e-12),
+            _ => panic!("expected Speed::MetersPerSecond variant"),
+        }
+    }
+
+    #[test]
+    fn test_from_base() {
+        let speed = Speed::MetersPerSecond(0.44704);
+        match speed.to(SpeedUnit::mph) {
+            Speed::MilesPerHour(x) => assert!((x - 1.0).abs() < 1e-12),
+            _ => panic!("expected Speed::MilesPerHour variant"),
+        }
+        let knot = Speed::Knots(1.0);
+        assert!((knot.to(SpeedUnit::kph).value() - 1.852).abs() < 1e-12);
+        let mach = Speed::Mach(1.0);
+        assert!((mach.to(SpeedUnit::MetersPerSecond).value() - 340.294).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cmp() {
+        assert!(Speed::MilesPerHour(60.0) > Speed::KilometersPerHour(90.0));
+        assert!(Speed::Knots(1.0) > Speed::MilesPerHour(1.0));
+        assert!(Speed::Mach(1.0) > Speed::FeetPerSecond(1000.0));
+        assert_eq!(Speed::KilometersPerHour(1.0e6), Speed::MetersPerSecond(1.0e6 / 3.6));
+        assert_eq!(Speed::Knots(1.0e5), Speed::KilometersPerHour(1.852e5));
+    }
+
+    #[test]
+    fn test_add_sub() {
+        match Speed::MetersPerSecond(10.0) + Speed::KilometersPerHour(36.0) {
+            Speed::MetersPerSecond(x) => assert!((x - 20.0).abs() < 1e-12),
+            _ => panic!("expected Speed::MetersPerSecond variant"),
+        }
+        match Speed::KilometersPerHour(100.0) - Speed::MetersPerSecond(10.0) {
+            Speed::KilometersPerHour(x) => assert!((x - 64.0).abs() < 1e-12),
+            _ => panic!("expected Speed::KilometersPerHour variant"),
+        }
+    }
+
+    #[test]
+    fn test_scalar_ops() {
+        let speed = Speed::Knots(12.0);
+        assert_eq!(speed * 2.0, Speed::Knots(24.0));
+        assert_eq!(2.0 * speed, Speed::Knots(24.0));
+        assert_eq!(speed / 3.0, Speed::Knots(4.0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Speed::MetersPerSecond(3.5)), "3.5 m/s");
+        assert_eq!(format!("{}", Speed::KilometersPerHour(100.0)), "100 km/h");
+        assert_eq!(format!("{}", Speed::MilesPerHour(60.0)), "60 mph");
+        assert_eq!(format!("{}", Speed::Mach(2.0)), "2 Mach");
+    }
+
+    #[test]
+    fn test_length_div_time() {
+        match Length::Kilometers(150.0) / Time::Hours(1.5) {
+            Speed::KilometersPerHour(x) => assert_eq!(x, 100.0),
+            _ => panic!("expected Speed::KilometersPerHour variant"),
+        }
+        match Length::Meters(100.0) / Time::Seconds(9.58) {
+            Speed::MetersPerSecond(x) => assert!((x - 10.438).abs() < 1e-3),
+            _ => panic!("expected Speed::MetersPerSecond variant"),
+        }
+        match Length::NauticalMiles(30.0) / Time::Minutes(120.0) {
+            Speed::Knots(x) => assert_eq!(x, 15.0),
+            _ => panic!("expected Speed::Knots variant"),
+        }
+    }
+
+    #[test]
+    fn test_speed_times_time() {
+        match Speed::MilesPerHour(60.0) * Time::Minutes(30.0) {
+            Length::Miles(x) => assert_eq!(x, 30.0),
+            _ => panic!("expected Length::Miles variant"),
+        }
+        match Speed::MetersPerSecond(3.0) * Time::Minutes(1.0) {
+            Length::Meters(x) => assert_eq!(x, 180.0),
+            _ => panic!("expected Length::Meters variant"),
+        }
+    }
+
+    #[test]
+    fn test_length_div_speed() {
+        let time = Length::Kilometers(100.0) / Speed::KilometersPerHour(50.0);
+        assert!((time.to(TimeUnit::Hours).value() - 2.0).abs() < 1e-12);
+        let time = Length::Meters(340.294) / Speed::Mach(1.0);
+        assert!((time.to_base().value() - 1.0).abs() < 1e-12);
+    }
+}
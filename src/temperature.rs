@@ -0,0 +1,510 @@
+//! This module contains the `Temperature` and `TemperatureDelta` enums and their associated unit
+//! conversions. Both are expressed in kelvin, degrees Celsius, degrees Fahrenheit or degrees
+//! Rankine and share the `TemperatureUnit` enum to specify the unit.
+//!
+//! Unlike the other dimensions, converting an absolute temperature is affine rather than a
+//! single multiplication, since the Celsius and Fahrenheit scales are offset from absolute zero.
+//! A `TemperatureDelta` is the difference between two temperatures and converts with the scale
+//! factor alone. Subtracting two temperatures yields a delta, and a delta can be added to or
+//! subtracted from a temperature. Adding two absolute temperatures is not meaningful and is
+//! rejected at compile time.
+
+use std::ops::{Add, Sub, Mul, Div};
+use std::fmt::Display;
+use crate::unit_traits::UnitConversion;
+use crate::unit_traits::Unit;
+use crate::utils;
+
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[allow(non_camel_case_types)]
+pub enum TemperatureUnit {
+    Kelvin,
+    K,
+    Celsius,
+    degC,
+    Fahrenheit,
+    degF,
+    Rankine,
+    degR,
+}
+
+
+/// The `Temperature` enum represents absolute temperatures in kelvin, degrees Celsius, degrees
+/// Fahrenheit or degrees Rankine.
+///
+/// Two absolute temperatures cannot be added together:
+///
+/// ```compile_fail
+/// use unit_types::Temperature;
+/// let _ = Temperature::Celsius(20.0) + Temperature::Celsius(5.0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub enum Temperature {
+    Kelvin(f64),
+    Celsius(f64),
+    Fahrenheit(f64),
+    Rankine(f64),
+}
+
+/// The `TemperatureDelta` enum represents a difference between two temperatures in kelvin,
+/// degrees Celsius, degrees Fahrenheit or degrees Rankine.
+#[derive(Debug, Clone, Copy)]
+pub enum TemperatureDelta {
+    Kelvin(f64),
+    Celsius(f64),
+    Fahrenheit(f64),
+    Rankine(f64),
+}
+
+impl Temperature {
+    /// The temperature of 0 °C in kelvin.
+    pub const KELVIN_AT_ZERO_CELSIUS: f64 = 273.15;
+    /// The temperature of 0 °F in degrees Rankine.
+    pub const RANKINE_AT_ZERO_FAHRENHEIT: f64 = 459.67;
+    /// The temperature of 0 °C in degrees Fahrenheit.
+    pub const FAHRENHEIT_AT_ZERO_CELSIUS: f64 = 32.0;
+    /// The number of degrees Rankine (or Fahrenheit) in one kelvin. Conversions multiply or
+    /// divide by this exact factor rather than by its inexact reciprocal, 5/9.
+    pub const RANKINE_PER_KELVIN: f64 = 1.8;
+    /// The size of one degree Rankine (or Fahrenheit) in kelvin. Kept for reference; the
+    /// conversions use `RANKINE_PER_KELVIN`, since 5/9 has no exact `f64` representation.
+    pub const KELVIN_PER_RANKINE: f64 = 1.0 / Temperature::RANKINE_PER_KELVIN;
+
+    /* ALIASES */
+
+    #[allow(non_snake_case)]
+    pub fn K(x: f64) -> Temperature {
+        Temperature::Kelvin(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn degC(x: f64) -> Temperature {
+        Temperature::Celsius(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn degF(x: f64) -> Temperature {
+        Temperature::Fahrenheit(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn degR(x: f64) -> Temperature {
+        Temperature::Rankine(x)
+    }
+}
+
+impl Unit for Temperature {
+    /// Returns the inner f64 value of the Temperature enum.
+    fn value(&self) -> f64 {
+        match self {
+            Temperature::Kelvin(x) => *x,
+            Temperature::Celsius(x) => *x,
+            Temperature::Fahrenheit(x) => *x,
+            Temperature::Rankine(x) => *x,
+        }
+    }
+}
+
+impl UnitConversion for Temperature {
+    type Units = TemperatureUnit;
+
+    /// Returns the unit the Temperature is expressed in.
+    fn unit(&self) -> TemperatureUnit {
+        match self {
+            Temperature::Kelvin(_) => TemperatureUnit::Kelvin,
+            Temperature::Celsius(_) => TemperatureUnit::Celsius,
+            Temperature::Fahrenheit(_) => TemperatureUnit::Fahrenheit,
+            Temperature::Rankine(_) => TemperatureUnit::Rankine,
+        }
+    }
+
+    /// Converts a given instance of a Temperature enum into the base variant,
+    /// `Temperature::Kelvin`, applying the offset of the source scale.
+    fn to_base(&self) -> Temperature {
+        match self {
+            Temperature::Kelvin(x) => Temperature::Kelvin(*x),
+            Temperature::Celsius(x) => Temperature::Kelvin(x + Temperature::KELVIN_AT_ZERO_CELSIUS),
+            Temperature::Fahrenheit(x) => Temperature::Kelvin(
+                (x - Temperature::FAHRENHEIT_AT_ZERO_CELSIUS) / Temperature::RANKINE_PER_KELVIN
+                    + Temperature::KELVIN_AT_ZERO_CELSIUS
+            ),
+            Temperature::Rankine(x) => Temperature::Kelvin(x / Temperature::RANKINE_PER_KELVIN),
+        }
+    }
+
+    /// Converts a given instance of a Temperature enum into the specified variant as indicated
+    /// by the `TemperatureUnit` provided. Fahrenheit is reached through the Celsius offset, so
+    /// the fixed points such as 100 °C and 212 °F convert exactly.
+    fn to(&self, variant: TemperatureUnit) -> Temperature {
+        // Fahrenheit and Rankine share a degree size, so they convert by the offset alone.
+        match (self, variant) {
+            (Temperature::Fahrenheit(x), TemperatureUnit::Rankine | TemperatureUnit::degR) => {
+                return Temperature::Rankine(x + Temperature::RANKINE_AT_ZERO_FAHRENHEIT);
+            }
+            (Temperature::Rankine(x), TemperatureUnit::Fahrenheit | TemperatureUnit::degF) => {
+                return Temperature::Fahrenheit(x - Temperature::RANKINE_AT_ZERO_FAHRENHEIT);
+            }
+            _ => {}
+        }
+
+        let kelvin = self.to_base().value();
+        match variant {
+            TemperatureUnit::Kelvin | TemperatureUnit::K => Temperature::Kelvin(kelvin),
+            TemperatureUnit::Celsius | TemperatureUnit::degC => {
+                Temperature::Celsius(kelvin - Temperature::KELVIN_AT_ZERO_CELSIUS)
+            }
+            TemperatureUnit::Fahrenheit | TemperatureUnit::degF => Temperature::Fahrenheit(
+                (kelvin - Temperature::KELVIN_AT_ZERO_CELSIUS) * Temperature::RANKINE_PER_KELVIN
+                    + Temperature::FAHRENHEIT_AT_ZERO_CELSIUS
+            ),
+            TemperatureUnit::Rankine | TemperatureUnit::degR => {
+                Temperature::Rankine(kelvin * Temperature::RANKINE_PER_KELVIN)
+            }
+        }
+    }
+}
+
+impl PartialEq for Temperature {
+    fn eq(&self, other: &Temperature) -> bool {
+        let a = self.to_base().value();
+        let b = other.to_base().value();
+        utils::approx_eq(a, b)
+    }
+}
+
+impl PartialOrd for Temperature {
+    fn partial_cmp(&self, other: &Temperature) -> Option<std::cmp::Ordering> {
+        let a = self.to_base().value();
+        let b = other.to_base().value();
+        utils::approx_cmp(a, b)
+    }
+}
+
+
+impl Sub for Temperature {
+    type Output = TemperatureDelta;
+
+    /// Subtracts two temperatures, producing a delta in the unit of the left operand.
+    fn sub(self, other: Temperature) -> TemperatureDelta {
+        match self {
+            Temperature::Kelvin(x) => {
+                TemperatureDelta::Kelvin(x - other.to(TemperatureUnit::Kelvin).value())
+            }
+            Temperature::Celsius(x) => {
+                TemperatureDelta::Celsius(x - other.to(TemperatureUnit::Celsius).value())
+            }
+            Temperature::Fahrenheit(x) => {
+                TemperatureDelta::Fahrenheit(x - other.to(TemperatureUnit::Fahrenheit).value())
+            }
+            Temperature::Rankine(x) => {
+                TemperatureDelta::Rankine(x - other.to(TemperatureUnit::Rankine).value())
+            }
+        }
+    }
+}
+
+impl Add<TemperatureDelta> for Temperature {
+    type Output = Temperature;
+
+    /// Shifts a temperature by a delta, preserving the unit of the temperature.
+    fn add(self, other: TemperatureDelta) -> Temperature {
+        match self {
+            Temperature::Kelvin(x) => {
+                Temperature::Kelvin(x + other.to(TemperatureUnit::Kelvin).value())
+            }
+            Temperature::Celsius(x) => {
+                Temperature::Celsius(x + other.to(TemperatureUnit::Celsius).value())
+            }
+            Temperature::Fahrenheit(x) => {
+                Temperature::Fahrenheit(x + other.to(TemperatureUnit::Fahrenheit).value())
+            }
+            Temperature::Rankine(x) => {
+                Temperature::Rankine(x + other.to(TemperatureUnit::Rankine).value())
+            }
+        }
+    }
+}
+
+impl Sub<TemperatureDelta> for Temperature {
+    type Output = Temperature;
+
+    /// Shifts a temperature down by a delta, preserving the unit of the temperature.
+    fn sub(self, other: TemperatureDelta) -> Temperature {
+        self + other * -1.0
+    }
+}
+
+
+impl Display for Temperature {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (x, symbol, name) = match self {
+            Temperature::Kelvin(x) => (x, "K", "kelvin"),
+            Temperature::Celsius(x) => (x, "°C", "degrees Celsius"),
+            Temperature::Fahrenheit(x) => (x, "°F", "degrees Fahrenheit"),
+            Temperature::Rankine(x) => (x, "°R", "degrees Rankine"),
+        };
+        utils::fmt_quantity(f, *x, symbol, name)
+    }
+}
+
+
+impl TemperatureDelta {
+    /* ALIASES */
+
+    #[allow(non_snake_case)]
+    pub fn K(x: f64) -> TemperatureDelta {
+        TemperatureDelta::Kelvin(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn degC(x: f64) -> TemperatureDelta {
+        TemperatureDelta::Celsius(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn degF(x: f64) -> TemperatureDelta {
+        TemperatureDelta::Fahrenheit(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn degR(x: f64) -> TemperatureDelta {
+        TemperatureDelta::Rankine(x)
+    }
+}
+
+impl Unit for TemperatureDelta {
+    /// Returns the inner f64 value of the TemperatureDelta enum.
+    fn value(&self) -> f64 {
+        match self {
+            TemperatureDelta::Kelvin(x) => *x,
+            TemperatureDelta::Celsius(x) => *x,
+            TemperatureDelta::Fahrenheit(x) => *x,
+            TemperatureDelta::Rankine(x) => *x,
+        }
+    }
+}
+
+impl UnitConversion for TemperatureDelta {
+    type Units = TemperatureUnit;
+
+    /// Returns the unit the TemperatureDelta is expressed in.
+    fn unit(&self) -> TemperatureUnit {
+        match self {
+            TemperatureDelta::Kelvin(_) => TemperatureUnit::Kelvin,
+            TemperatureDelta::Celsius(_) => TemperatureUnit::Celsius,
+            TemperatureDelta::Fahrenheit(_) => TemperatureUnit::Fahrenheit,
+            TemperatureDelta::Rankine(_) => TemperatureUnit::Rankine,
+        }
+    }
+
+    /// Converts a given instance of a TemperatureDelta enum into the base variant,
+    /// `TemperatureDelta::Kelvin`. Only the scale factor applies, never the offset.
+    fn to_base(&self) -> TemperatureDelta {
+        match self {
+            TemperatureDelta::Kelvin(x) => TemperatureDelta::Kelvin(*x),
+            TemperatureDelta::Celsius(x) => TemperatureDelta::Kelvin(*x),
+            TemperatureDelta::Fahrenheit(x) => {
+                TemperatureDelta::Kelvin(x / Temperature::RANKINE_PER_KELVIN)
+            }
+            TemperatureDelta::Rankine(x) => {
+                TemperatureDelta::Kelvin(x / Temperature::RANKINE_PER_KELVIN)
+            }
+        }
+    }
+
+    /// Converts a given instance of a TemperatureDelta enum into the specified variant as
+    /// indicated by the `TemperatureUnit` provided.
+    fn to(&self, variant: TemperatureUnit) -> TemperatureDelta {
+        let kelvin = self.to_base().value();
+        match variant {
+            TemperatureUnit::Kelvin | TemperatureUnit::K => TemperatureDelta::Kelvin(kelvin),
+            TemperatureUnit::Celsius | TemperatureUnit::degC => TemperatureDelta::Celsius(kelvin),
+            TemperatureUnit::Fahrenheit | TemperatureUnit::degF => {
+                TemperatureDelta::Fahrenheit(kelvin * Temperature::RANKINE_PER_KELVIN)
+            }
+            TemperatureUnit::Rankine | TemperatureUnit::degR => {
+                TemperatureDelta::Rankine(kelvin * Temperature::RANKINE_PER_KELVIN)
+            }
+        }
+    }
+}
+
+
+impl Mul<f64> for TemperatureDelta {
+    type Output = TemperatureDelta;
+
+    fn mul(self, other: f64) -> TemperatureDelta {
+        match self {
+            TemperatureDelta::Kelvin(x) => TemperatureDelta::Kelvin(x * other),
+            TemperatureDelta::Celsius(x) => TemperatureDelta::Celsius(x * other),
+            TemperatureDelta::Fahrenheit(x) => TemperatureDelta::Fahrenheit(x * other),
+            TemperatureDelta::Rankine(x) => TemperatureDelta::Rankine(x * other),
+        }
+    }
+}
+
+
+impl Mul<TemperatureDelta> for f64 {
+    type Output = TemperatureDelta;
+
+    fn mul(self, other: TemperatureDelta) -> TemperatureDelta {
+        other * self
+    }
+}
+
+
+impl Div<f64> for TemperatureDelta {
+    type Output = TemperatureDelta;
+
+    fn div(self, other: f64) -> TemperatureDelta {
+        match self {
+            TemperatureDelta::Kelvin(x) => TemperatureDelta::Kelvin(x / other),
+            TemperatureDelta::Celsius(x) => TemperatureDelta::Celsius(x / other),
+            TemperatureDelta::Fahrenheit(x) => TemperatureDelta::Fahrenheit(x / other),
+            TemperatureDelta::Rankine(x) => TemperatureDelta::Rankine(x / other),
+        }
+    }
+}
+
+
+impl Display for TemperatureDelta {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (x, symbol, name) = match self {
+            TemperatureDelta::Kelvin(x) => (x, "K", "kelvin"),
+            TemperatureDelta::Celsius(x) => (x, "°C", "degrees Celsius"),
+            TemperatureDelta::Fahrenheit(x) => (x, "°F", "degrees Fahrenheit"),
+            TemperatureDelta::Rankine(x) => (x, "°R", "degrees Rankine"),
+        };
+        utils::fmt_quantity(f, *x, symbol, name)
+    }
+}
+
+
+/*--------------------( Unit Tests )--------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::assert_close;
+
+    #[test]
+    fn test_to_base() {
+        match Temperature::Celsius(25.0).to_base() {
+            Temperature::Kelvin(x) => assert_eq!(x, 298.15),
+            _ => panic!("expected Temperature::Kelvin variant"),
+        }
+        assert_close(Temperature::Fahrenheit(32.0).to_base().value(), 273.15);
+        assert_close(Temperature::Rankine(491.67).to_base().value(), 273.15);
+    }
+
+    #[test]
+    fn test_from_base() {
+        let boiling = Temperature::Kelvin(373.15);
+        match boiling.to(TemperatureUnit::degF) {
+            Temperature::Fahrenheit(x) => assert_close(x, 212.0),
+            _ => panic!("expected Temperature::Fahrenheit variant"),
+        }
+        assert_close(boiling.to(TemperatureUnit::Celsius).value(), 100.0);
+        assert_close(boiling.to(TemperatureUnit::Rankine).value(), 671.67);
+    }
+
+    #[test]
+    fn test_fixed_points() {
+        // -40 is the same on both the Celsius and Fahrenheit scales
+        assert_close(Temperature::Celsius(-40.0).to(TemperatureUnit::Fahrenheit).value(), -40.0);
+        assert_close(Temperature::Kelvin(0.0).to(TemperatureUnit::Fahrenheit).value(), -459.67);
+        assert_close(Temperature::Kelvin(0.0).to(TemperatureUnit::Celsius).value(), -273.15);
+    }
+
+    #[test]
+    fn test_cmp() {
+        assert!(Temperature::Celsius(0.0) < Temperature::Fahrenheit(33.0));
+        assert!(Temperature::Kelvin(300.0) > Temperature::Celsius(26.0));
+        assert_eq!(Temperature::Celsius(100.0), Temperature::Kelvin(373.15));
+        assert_eq!(Temperature::Celsius(100.0), Temperature::Fahrenheit(212.0));
+        assert_eq!(Temperature::Celsius(-40.0), Temperature::Fahrenheit(-40.0));
+        assert_eq!(Temperature::Fahrenheit(32.0), Temperature::Rankine(491.67));
+        assert_ne!(Temperature::Kelvin(300.0), Temperature::Kelvin(300.000001));
+    }
+
+    #[test]
+    fn test_exact_fixed_points() {
+        assert_eq!(Temperature::Celsius(100.0).to(TemperatureUnit::Fahrenheit).value(), 212.0);
+        assert_eq!(Temperature::Celsius(-40.0).to(TemperatureUnit::Fahrenheit).value(), -40.0);
+        assert_eq!(Temperature::Celsius(0.0).to(TemperatureUnit::Fahrenheit).value(), 32.0);
+        assert_eq!(Temperature::Fahrenheit(212.0).to(TemperatureUnit::Celsius).value(), 100.0);
+        assert_eq!(TemperatureDelta::Kelvin(10.0).to(TemperatureUnit::Fahrenheit).value(), 18.0);
+        assert_eq!(Temperature::Fahrenheit(32.0).to(TemperatureUnit::Rankine).value(), 491.67);
+        assert_eq!(Temperature::Rankine(671.67).to(TemperatureUnit::degF), Temperature::Fahrenheit(212.0));
+        assert_eq!(Temperature::KELVIN_PER_RANKINE, 5.0 / 9.0);
+    }
+
+    #[test]
+    fn test_sub_yields_delta() {
+        match Temperature::Celsius(30.0) - Temperature::Celsius(20.0) {
+            TemperatureDelta::Celsius(x) => assert_eq!(x, 10.0),
+            _ => panic!("expected TemperatureDelta::Celsius variant"),
+        }
+        match Temperature::Fahrenheit(212.0) - Temperature::Celsius(0.0) {
+            TemperatureDelta::Fahrenheit(x) => assert_close(x, 180.0),
+            _ => panic!("expected TemperatureDelta::Fahrenheit variant"),
+        }
+    }
+
+    #[test]
+    fn test_add_delta() {
+        match Temperature::Celsius(20.0) + TemperatureDelta::Kelvin(5.0) {
+            Temperature::Celsius(x) => assert_eq!(x, 25.0),
+            _ => panic!("expected Temperature::Celsius variant"),
+        }
+        match Temperature::Fahrenheit(50.0) + TemperatureDelta::Celsius(10.0) {
+            Temperature::Fahrenheit(x) => assert_close(x, 68.0),
+            _ => panic!("expected Temperature::Fahrenheit variant"),
+        }
+        match Temperature::Kelvin(300.0) - TemperatureDelta::Fahrenheit(9.0) {
+            Temperature::Kelvin(x) => assert_close(x, 295.0),
+            _ => panic!("expected Temperature::Kelvin variant"),
+        }
+    }
+
+    #[test]
+    fn test_delta_conversions() {
+        let delta = TemperatureDelta::Celsius(10.0);
+        assert_eq!(delta.to(TemperatureUnit::Kelvin).value(), 10.0);
+        assert_close(delta.to(TemperatureUnit::Fahrenheit).value(), 18.0);
+        assert_close(delta.to(TemperatureUnit::Rankine).value(), 18.0);
+        assert_eq!(TemperatureDelta::Fahrenheit(9.0), TemperatureDelta::Kelvin(5.0));
+    }
+
+    #[test]
+    fn test_delta_arithmetic() {
+        match TemperatureDelta::Celsius(10.0) + TemperatureDelta::Kelvin(5.0) {
+            TemperatureDelta::Celsius(x) => assert_eq!(x, 15.0),
+            _ => panic!("expected TemperatureDelta::Celsius variant"),
+        }
+        match TemperatureDelta::Fahrenheit(18.0) - TemperatureDelta::Celsius(5.0) {
+            TemperatureDelta::Fahrenheit(x) => assert_close(x, 9.0),
+            _ => panic!("expected TemperatureDelta::Fahrenheit variant"),
+        }
+        assert_eq!(TemperatureDelta::Kelvin(2.0) * 3.0, TemperatureDelta::Kelvin(6.0));
+        assert_eq!(3.0 * TemperatureDelta::Kelvin(2.0), TemperatureDelta::Kelvin(6.0));
+        assert_eq!(TemperatureDelta::Kelvin(6.0) / 3.0, TemperatureDelta::Kelvin(2.0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Temperature::Kelvin(300.0)), "300 K");
+        assert_eq!(format!("{}", Temperature::Celsius(21.5)), "21.5 °C");
+        assert_eq!(format!("{}", Temperature::Fahrenheit(70.0)), "70 °F");
+        assert_eq!(format!("{}", TemperatureDelta::Rankine(1.0)), "1 °R");
+    }
+
+    #[test]
+    fn test_display_format() {
+        assert_eq!(format!("{:.1}", Temperature::Celsius(21.456)), "21.5 °C");
+        assert_eq!(format!("{:#}", Temperature::Fahrenheit(-40.0)), "-40 degrees Fahrenheit");
+        assert_eq!(format!("{:+.1}", TemperatureDelta::Kelvin(2.0)), "+2.0 K");
+        assert_eq!(format!("{:^9}", Temperature::Kelvin(300.0)), "  300 K  ");
+    }
+}
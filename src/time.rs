@@ -1,42 +1,171 @@
 use std::ops::{Add, Sub, Mul, Div};
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
 
 use crate::prelude::UnitConversion;
 use crate::prelude::Unit;
+use crate::parse::{split_components, split_quantity, ParseUnitError};
+use crate::utils;
 
 pub enum TimeUnit {
+    Nanoseconds,
+    Microseconds,
+    Milliseconds,
     Seconds,
     Minutes,
     Hours,
     Days,
+    SiderealDays,
+    Weeks,
+    Fortnights,
     Years,
+    JulianYears,
+    GregorianYears,
 }
 
 #[derive(Debug, Copy, Clone)]
 pub enum Time {
+    Nanoseconds(f64),
+    Microseconds(f64),
+    Milliseconds(f64),
     Seconds(f64),
     Minutes(f64),
     Hours(f64),
     Days(f64),
+    SiderealDays(f64),
+    Weeks(f64),
+    Fortnights(f64),
     Years(f64),
+    JulianYears(f64),
+    GregorianYears(f64),
 }
 
 
 impl Time {
+    pub const SECONDS_PER_NANOSECOND: f64 = 1.0e-9;
+    pub const SECONDS_PER_MICROSECOND: f64 = 1.0e-6;
+    pub const SECONDS_PER_MILLISECOND: f64 = 1.0e-3;
     pub const SECONDS_PER_MINUTE: f64 = 60.0;
     pub const SECONDS_PER_HOUR: f64 = 3600.0;
     pub const SECONDS_PER_DAY: f64 = 86400.0;
+    /// The mean sidereal day, one rotation of the Earth relative to the fixed stars. It follows
+    /// from the rate of the Earth rotation angle in the IERS Conventions (2010), which is
+    /// 1.00273781191135448 rotations per day.
+    pub const SECONDS_PER_SIDEREAL_DAY: f64 = Time::SECONDS_PER_DAY / 1.002_737_811_911_354_6;
+    pub const SECONDS_PER_WEEK: f64 = 7.0 * 86400.0;
+    pub const SECONDS_PER_FORTNIGHT: f64 = 14.0 * 86400.0;
+    /// A fixed 365-day year.
     pub const SECONDS_PER_YEAR: f64 = 31536000.0;
+    /// The Julian year of 365.25 days, used in astronomy.
+    pub const SECONDS_PER_JULIAN_YEAR: f64 = 365.25 * 86400.0;
+    /// The mean Gregorian calendar year of 365.2425 days.
+    pub const SECONDS_PER_GREGORIAN_YEAR: f64 = 365.2425 * 86400.0;
+
+    /// Parses a compound duration such as `"1h 30m 15s"` or `"2d4h"` by summing its components,
+    /// each written with any of the units accepted by `Time::from_str`. A leading `-` negates the
+    /// whole duration. The result is expressed in seconds.
+    pub fn parse_compound(s: &str) -> Result<Time, ParseUnitError> {
+        let s = s.trim();
+        let (sign, rest) = match s.strip_prefix('-') {
+            Some(rest) => (-1.0, rest),
+            None => (1.0, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        let mut seconds = 0.0;
+        for (x, unit) in split_components(rest)? {
+            seconds += Time::from_unit_str(x, unit)?.to_base().value();
+        }
+        Ok(Time::Seconds(sign * seconds))
+    }
+
+    /// Formats the time largest unit first in days, hours, minutes and seconds, omitting zero
+    /// components, e.g. `"1d 2h 30m 15.5s"`. The output is accepted by `Time::parse_compound`.
+    ///
+    /// The time is rounded to the nearest nanosecond before it is split up, so floating point
+    /// leftovers such as those of `Time::Hours(1.1)` do not show up as a stray fraction of a
+    /// second.
+    pub fn to_compound_string(&self) -> String {
+        const NANOSECONDS_PER_SECOND: u128 = 1_000_000_000;
+        let seconds = self.to_base().value();
+        let nanoseconds = (seconds.abs() * NANOSECONDS_PER_SECOND as f64).round();
+        if !nanoseconds.is_finite() || nanoseconds >= u128::MAX as f64 {
+            return format!("{}s", seconds);
+        }
+
+        let mut rest = nanoseconds as u128;
+        let mut parts = Vec::new();
+        for (factor, symbol) in [(Time::SECONDS_PER_DAY, "d"), (Time::SECONDS_PER_HOUR, "h"), (Time::SECONDS_PER_MINUTE, "m")] {
+            let factor = factor as u128 * NANOSECONDS_PER_SECOND;
+            let count = rest / factor;
+            if count > 0 {
+                parts.push(format!("{}{}", count, symbol));
+                rest %= factor;
+            }
+        }
+        if rest > 0 || parts.is_empty() {
+            parts.push(format!("{}s", rest as f64 / NANOSECONDS_PER_SECOND as f64));
+        }
+
+        let compound = parts.join(" ");
+        if seconds < 0.0 && nanoseconds > 0.0 { format!("-{}", compound) } else { compound }
+    }
+
+    /// Builds a `Time` from a number and a unit token such as `"h"` or `"minutes"`. Unit symbols
+    /// are case sensitive, so `"MS"` is rejected rather than read as milliseconds, while
+    /// spelled-out unit names are not.
+    fn from_unit_str(x: f64, unit: &str) -> Result<Time, ParseUnitError> {
+        let time = match unit {
+            "ns" => Time::Nanoseconds(x),
+            "µs" | "us" => Time::Microseconds(x),
+            "ms" => Time::Milliseconds(x),
+            "s" => Time::Seconds(x),
+            "m" | "min" => Time::Minutes(x),
+            "h" | "hr" => Time::Hours(x),
+            "d" => Time::Days(x),
+            "sidereal d" => Time::SiderealDays(x),
+            "wk" => Time::Weeks(x),
+            "y" | "yr" => Time::Years(x),
+            "Julian yr" => Time::JulianYears(x),
+            "Gregorian yr" => Time::GregorianYears(x),
+            _ => match unit.to_lowercase().as_str() {
+                "nanosecond" | "nanoseconds" => Time::Nanoseconds(x),
+                "microsecond" | "microseconds" => Time::Microseconds(x),
+                "millisecond" | "milliseconds" => Time::Milliseconds(x),
+                "sec" | "secs" | "second" | "seconds" => Time::Seconds(x),
+                "mins" | "minute" | "minutes" => Time::Minutes(x),
+                "hrs" | "hour" | "hours" => Time::Hours(x),
+                "day" | "days" => Time::Days(x),
+                "sidereal day" | "sidereal days" | "siderealdays" => Time::SiderealDays(x),
+                "wks" | "week" | "weeks" => Time::Weeks(x),
+                "fortnight" | "fortnights" => Time::Fortnights(x),
+                "yrs" | "year" | "years" => Time::Years(x),
+                "julian year" | "julian years" | "julianyears" => Time::JulianYears(x),
+                "gregorian year" | "gregorian years" | "gregorianyears" => Time::GregorianYears(x),
+                _ => return Err(ParseUnitError::UnknownUnit(unit.to_string())),
+            },
+        };
+        Ok(time)
+    }
 }
 
 
 impl Unit for Time {
     fn value(&self) -> f64 {
         match self {
+            Time::Nanoseconds(x) => *x,
+            Time::Microseconds(x) => *x,
+            Time::Milliseconds(x) => *x,
             Time::Seconds(x) => *x,
             Time::Minutes(x) => *x,
             Time::Hours(x) => *x,
             Time::Days(x) => *x,
+            Time::SiderealDays(x) => *x,
+            Time::Weeks(x) => *x,
+            Time::Fortnights(x) => *x,
             Time::Years(x) => *x,
+            Time::JulianYears(x) => *x,
+            Time::GregorianYears(x) => *x,
         }
     }
 }
@@ -45,142 +174,82 @@ impl Unit for Time {
 impl UnitConversion for Time {
     type Units = TimeUnit;
 
+    /// Returns the unit the Time is expressed in.
+    fn unit(&self) -> TimeUnit {
+        match self {
+            Time::Nanoseconds(_) => TimeUnit::Nanoseconds,
+            Time::Microseconds(_) => TimeUnit::Microseconds,
+            Time::Milliseconds(_) => TimeUnit::Milliseconds,
+            Time::Seconds(_) => TimeUnit::Seconds,
+            Time::Minutes(_) => TimeUnit::Minutes,
+            Time::Hours(_) => TimeUnit::Hours,
+            Time::Days(_) => TimeUnit::Days,
+            Time::SiderealDays(_) => TimeUnit::SiderealDays,
+            Time::Weeks(_) => TimeUnit::Weeks,
+            Time::Fortnights(_) => TimeUnit::Fortnights,
+            Time::Years(_) => TimeUnit::Years,
+            Time::JulianYears(_) => TimeUnit::JulianYears,
+            Time::GregorianYears(_) => TimeUnit::GregorianYears,
+        }
+    }
+
     fn to_base(&self) -> Time {
         match self {
+            Time::Nanoseconds(x) => Time::Seconds(x * Time::SECONDS_PER_NANOSECOND),
+            Time::Microseconds(x) => Time::Seconds(x * Time::SECONDS_PER_MICROSECOND),
+            Time::Milliseconds(x) => Time::Seconds(x * Time::SECONDS_PER_MILLISECOND),
             Time::Seconds(x) => Time::Seconds(*x),
             Time::Minutes(x) => Time::Seconds(x * Time::SECONDS_PER_MINUTE),
             Time::Hours(x) => Time::Seconds(x * Time::SECONDS_PER_HOUR),
             Time::Days(x) => Time::Seconds(x * Time::SECONDS_PER_DAY),
+            Time::SiderealDays(x) => Time::Seconds(x * Time::SECONDS_PER_SIDEREAL_DAY),
+            Time::Weeks(x) => Time::Seconds(x * Time::SECONDS_PER_WEEK),
+            Time::Fortnights(x) => Time::Seconds(x * Time::SECONDS_PER_FORTNIGHT),
             Time::Years(x) => Time::Seconds(x * Time::SECONDS_PER_YEAR),
+            Time::JulianYears(x) => Time::Seconds(x * Time::SECONDS_PER_JULIAN_YEAR),
+            Time::GregorianYears(x) => Time::Seconds(x * Time::SECONDS_PER_GREGORIAN_YEAR),
         }
     }
 
     fn to(&self, unit: TimeUnit) -> Time {
         let base = self.to_base();
         match unit {
+            TimeUnit::Nanoseconds => Time::Nanoseconds(base.value() / Time::SECONDS_PER_NANOSECOND),
+            TimeUnit::Microseconds => Time::Microseconds(base.value() / Time::SECONDS_PER_MICROSECOND),
+            TimeUnit::Milliseconds => Time::Milliseconds(base.value() / Time::SECONDS_PER_MILLISECOND),
             TimeUnit::Seconds => base,
             TimeUnit::Minutes => Time::Minutes(base.value() / Time::SECONDS_PER_MINUTE),
             TimeUnit::Hours => Time::Hours(base.value() / Time::SECONDS_PER_HOUR),
             TimeUnit::Days => Time::Days(base.value() / Time::SECONDS_PER_DAY),
+            TimeUnit::SiderealDays => Time::SiderealDays(base.value() / Time::SECONDS_PER_SIDEREAL_DAY),
+            TimeUnit::Weeks => Time::Weeks(base.value() / Time::SECONDS_PER_WEEK),
+            TimeUnit::Fortnights => Time::Fortnights(base.value() / Time::SECONDS_PER_FORTNIGHT),
             TimeUnit::Years => Time::Years(base.value() / Time::SECONDS_PER_YEAR),
+            TimeUnit::JulianYears => Time::JulianYears(base.value() / Time::SECONDS_PER_JULIAN_YEAR),
+            TimeUnit::GregorianYears => Time::GregorianYears(base.value() / Time::SECONDS_PER_GREGORIAN_YEAR),
         }
     }
 }
 
-impl PartialEq for Time {
-    fn eq(&self, other: &Time) -> bool {
-        let a = self.to_base().value();
-        let b = other.to_base().value();
-        (a - b).abs() < f64::EPSILON
-    }
-}
-
-impl PartialOrd for Time {
-    fn partial_cmp(&self, other: &Time) -> Option<std::cmp::Ordering> {
-        let a = self.to_base().value();
-        let b = other.to_base().value();
-        (a - b).partial_cmp(&0.0)
-    }
-}
-
-impl Add for Time {
-    type Output = Time;
-
-    fn add(self, other: Time) -> Time {
-        match self {
-            Time::Seconds(x) => match other {
-                Time::Seconds(y) => Time::Seconds(x + y),
-                Time::Minutes(y) => Time::Seconds(x + y * Time::SECONDS_PER_MINUTE),
-                Time::Hours(y)   => Time::Seconds(x + y * Time::SECONDS_PER_HOUR),
-                Time::Days(y)    => Time::Seconds(x + y * Time::SECONDS_PER_DAY),
-                Time::Years(y)   => Time::Seconds(x + y * Time::SECONDS_PER_YEAR),
-            },
-            Time::Minutes(x) => match other {
-                Time::Seconds(y) => Time::Minutes(x + y / Time::SECONDS_PER_MINUTE),
-                Time::Minutes(y) => Time::Minutes(x + y),
-                Time::Hours(y)   => Time::Minutes(x + y * 60.0),
-                Time::Days(y)    => Time::Minutes(x + y * 60.0 * 24.0),
-                Time::Years(y)   => Time::Minutes(x + y * 60.0 * 24.0 * 365.0),
-            },
-            Time::Hours(x) => match other {
-                Time::Seconds(y) => Time::Hours(x + y / 3600.0),
-                Time::Minutes(y) => Time::Hours(x + y / 60.0),
-                Time::Hours(y)   => Time::Hours(x + y),
-                Time::Days(y)    => Time::Hours(x + y * 24.0),
-                Time::Years(y)   => Time::Hours(x + y * 24.0 * 365.0),
-            },
-            Time::Days(x) => match other {
-                Time::Seconds(y) => Time::Days(x + y / 86400.0),
-                Time::Minutes(y) => Time::Days(x + y / 1440.0),
-                Time::Hours(y)   => Time::Days(x + y / 24.0),
-                Time::Days(y)    => Time::Days(x + y),
-                Time::Years(y)   => Time::Days(x + y * 365.0),
-            },
-            Time::Years(x) => match other {
-                Time::Seconds(y) => Time::Years(x + y / (365.0 * 24.0 * 3600.0)),
-                Time::Minutes(y) => Time::Years(x + y / (365.0 * 24.0 * 60.0)),
-                Time::Hours(y)   => Time::Years(x + y / (365.0 * 24.0)),
-                Time::Days(y)    => Time::Years(x + y / 365.0),
-                Time::Years(y)   => Time::Years(x + y),
-            },
-        }
-    }
-}
-
-impl Sub for Time {
-    type Output = Time;
-
-    fn sub(self, other: Time) -> Time {
-        match self {
-            Time::Seconds(x) => match other {
-                Time::Seconds(y) => Time::Seconds(x - y),
-                Time::Minutes(y) => Time::Seconds(x - y * 60.0),
-                Time::Hours(y)   => Time::Seconds(x - y * 3600.0),
-                Time::Days(y)    => Time::Seconds(x - y * 3600.0 * 24.0),
-                Time::Years(y)   => Time::Seconds(x - y * 3600.0 * 24.0  * 365.0),
-            },
-            Time::Minutes(x) => match other {
-                Time::Seconds(y) => Time::Minutes(x - y / 60.0),
-                Time::Minutes(y) => Time::Minutes(x - y),
-                Time::Hours(y)   => Time::Minutes(x - y * 60.0),
-                Time::Days(y)    => Time::Minutes(x - y * 60.0 * 24.0),
-                Time::Years(y)   => Time::Minutes(x - y * 60.0 * 24.0 * 365.0),
-            },
-            Time::Hours(x) => match other {
-                Time::Seconds(y) => Time::Hours(x - y / 3600.0),
-                Time::Minutes(y) => Time::Hours(x - y / 60.0),
-                Time::Hours(y)   => Time::Hours(x - y),
-                Time::Days(y)    => Time::Hours(x - y * 24.0),
-                Time::Years(y)   => Time::Hours(x - y * 24.0 * 365.0),
-            },
-            Time::Days(x) => match other {
-                Time::Seconds(y) => Time::Days(x - y / 86400.0),
-                Time::Minutes(y) => Time::Days(x - y / 1440.0),
-                Time::Hours(y)   => Time::Days(x - y / 24.0),
-                Time::Days(y)    => Time::Days(x - y),
-                Time::Years(y)   => Time::Days(x - y * 365.0),
-            },
-            Time::Years(x) => match other {
-                Time::Seconds(y) => Time::Years(x - y / (365.0 * 24.0 * 3600.0)),
-                Time::Minutes(y) => Time::Years(x - y / (365.0 * 24.0 * 60.0)),
-                Time::Hours(y)   => Time::Years(x - y / (365.0 * 24.0)),
-                Time::Days(y)    => Time::Years(x - y / 365.0),
-                Time::Years(y)   => Time::Years(x - y),
-            },
-        }
-    }
-}
 
 impl Mul<f64> for Time {
     type Output = Time;
 
     fn mul(self, other: f64) -> Time {
         match self {
+            Time::Nanoseconds(x) => Time::Nanoseconds(x * other),
+            Time::Microseconds(x) => Time::Microseconds(x * other),
+            Time::Milliseconds(x) => Time::Milliseconds(x * other),
             Time::Seconds(x) => Time::Seconds(x * other),
             Time::Minutes(x) => Time::Minutes(x * other),
             Time::Hours(x) => Time::Hours(x * other),
             Time::Days(x) => Time::Days(x * other),
+            Time::SiderealDays(x) => Time::SiderealDays(x * other),
+            Time::Weeks(x) => Time::Weeks(x * other),
+            Time::Fortnights(x) => Time::Fortnights(x * other),
             Time::Years(x) => Time::Years(x * other),
+            Time::JulianYears(x) => Time::JulianYears(x * other),
+            Time::GregorianYears(x) => Time::GregorianYears(x * other),
         }
     }
 }
@@ -190,11 +259,19 @@ impl Mul<Time> for f64 {
 
     fn mul(self, other: Time) -> Time {
         match other {
+            Time::Nanoseconds(x) => Time::Nanoseconds(x * self),
+            Time::Microseconds(x) => Time::Microseconds(x * self),
+            Time::Milliseconds(x) => Time::Milliseconds(x * self),
             Time::Seconds(x) => Time::Seconds(x * self),
             Time::Minutes(x) => Time::Minutes(x * self),
             Time::Hours(x) => Time::Hours(x * self),
             Time::Days(x) => Time::Days(x * self),
+            Time::SiderealDays(x) => Time::SiderealDays(x * self),
+            Time::Weeks(x) => Time::Weeks(x * self),
+            Time::Fortnights(x) => Time::Fortnights(x * self),
             Time::Years(x) => Time::Years(x * self),
+            Time::JulianYears(x) => Time::JulianYears(x * self),
+            Time::GregorianYears(x) => Time::GregorianYears(x * self),
         }
     }
 }
@@ -205,15 +282,129 @@ impl Div<f64> for Time {
 
     fn div(self, other: f64) -> Time {
         match self {
+            Time::Nanoseconds(x) => Time::Nanoseconds(x / other),
+            Time::Microseconds(x) => Time::Microseconds(x / other),
+            Time::Milliseconds(x) => Time::Milliseconds(x / other),
             Time::Seconds(x) => Time::Seconds(x / other),
             Time::Minutes(x) => Time::Minutes(x / other),
             Time::Hours(x) => Time::Hours(x / other),
             Time::Days(x) => Time::Days(x / other),
+            Time::SiderealDays(x) => Time::SiderealDays(x / other),
+            Time::Weeks(x) => Time::Weeks(x / other),
+            Time::Fortnights(x) => Time::Fortnights(x / other),
             Time::Years(x) => Time::Years(x / other),
+            Time::JulianYears(x) => Time::JulianYears(x / other),
+            Time::GregorianYears(x) => Time::GregorianYears(x / other),
+        }
+    }
+}
+
+impl Display for Time {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (x, symbol, name) = match self {
+            Time::Nanoseconds(x) => (x, "ns", "nanoseconds"),
+            Time::Microseconds(x) => (x, "µs", "microseconds"),
+            Time::Milliseconds(x) => (x, "ms", "milliseconds"),
+            Time::Seconds(x) => (x, "s", "seconds"),
+            Time::Minutes(x) => (x, "min", "minutes"),
+            Time::Hours(x) => (x, "h", "hours"),
+            Time::Days(x) => (x, "d", "days"),
+            Time::SiderealDays(x) => (x, "sidereal d", "sidereal days"),
+            Time::Weeks(x) => (x, "wk", "weeks"),
+            Time::Fortnights(x) => (x, "fortnight", "fortnights"),
+            Time::Years(x) => (x, "yr", "years"),
+            Time::JulianYears(x) => (x, "Julian yr", "Julian years"),
+            Time::GregorianYears(x) => (x, "Gregorian yr", "Gregorian years"),
+        };
+        utils::fmt_quantity(f, *x, symbol, name)
+    }
+}
+
+
+impl FromStr for Time {
+    type Err = ParseUnitError;
+
+    /// Parses strings such as `"1.5h"`, `"90 s"` or `"3 days"`. Unit symbols are case
+    /// sensitive, while spelled-out unit names are not.
+    fn from_str(s: &str) -> Result<Time, ParseUnitError> {
+        let (x, unit) = split_quantity(s)?;
+        Time::from_unit_str(x, unit)
+    }
+}
+
+/// The error returned when a `Time` cannot be represented as a `std::time::Duration`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DurationError {
+    /// The time is negative, which a `Duration` cannot hold.
+    Negative,
+    /// The time is NaN.
+    NaN,
+    /// The time is infinite or exceeds `Duration::MAX`.
+    Overflow,
+}
+
+impl Display for DurationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DurationError::Negative => write!(f, "cannot convert a negative time to a duration"),
+            DurationError::NaN => write!(f, "cannot convert a NaN time to a duration"),
+            DurationError::Overflow => write!(f, "time is too large to fit in a duration"),
+        }
+    }
+}
+
+impl std::error::Error for DurationError {}
+
+
+impl From<Duration> for Time {
+    fn from(duration: Duration) -> Time {
+        Time::Seconds(duration.as_secs_f64())
+    }
+}
+
+impl TryFrom<Time> for Duration {
+    type Error = DurationError;
+
+    fn try_from(time: Time) -> Result<Duration, DurationError> {
+        let seconds = time.to_base().value();
+        if seconds.is_nan() {
+            return Err(DurationError::NaN);
+        }
+        if seconds < 0.0 {
+            return Err(DurationError::Negative);
+        }
+        Duration::try_from_secs_f64(seconds).map_err(|_| DurationError::Overflow)
+    }
+}
+
+impl Time {
+    /// Converts the time into a `Duration`, clamping negative and NaN times to `Duration::ZERO`
+    /// and times too large to represent to `Duration::MAX`.
+    pub fn as_duration_saturating(&self) -> Duration {
+        match Duration::try_from(*self) {
+            Ok(duration) => duration,
+            Err(DurationError::Overflow) => Duration::MAX,
+            Err(_) => Duration::ZERO,
         }
     }
 }
 
+impl Add<Duration> for Time {
+    type Output = Time;
+
+    fn add(self, other: Duration) -> Time {
+        self + Time::from(other)
+    }
+}
+
+impl Sub<Duration> for Time {
+    type Output = Time;
+
+    fn sub(self, other: Duration) -> Time {
+        self - Time::from(other)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -394,4 +585,239 @@ mod tests {
         }
 
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Time::Seconds(1.5)), "1.5 s");
+        assert_eq!(format!("{}", Time::Minutes(30.0)), "30 min");
+        assert_eq!(format!("{}", Time::Years(2.0)), "2 yr");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("1.5h".parse::<Time>(), Ok(Time::Hours(1.5)));
+        assert_eq!("90 s".parse::<Time>(), Ok(Time::Seconds(90.0)));
+        assert_eq!("3 Days".parse::<Time>(), Ok(Time::Days(3.0)));
+        assert_eq!("45 mins".parse::<Time>(), Ok(Time::Minutes(45.0)));
+        assert_eq!("250 ms".parse::<Time>(), Ok(Time::Milliseconds(250.0)));
+        assert_eq!("2 Milliseconds".parse::<Time>(), Ok(Time::Milliseconds(2.0)));
+        assert_eq!("1 Julian Year".parse::<Time>(), Ok(Time::JulianYears(1.0)));
+        match "2 yr".parse::<Time>() {
+            Ok(Time::Years(x)) => assert_eq!(x, 2.0),
+            _ => panic!("expected Time::Years variant"),
+        }
+
+        for time in [Time::Seconds(0.25), Time::Minutes(12.0), Time::Hours(-3.0), Time::Days(7.0), Time::Years(1.0)] {
+            let parsed: Time = time.to_string().parse().unwrap();
+            assert_eq!(parsed.to_string(), time.to_string());
+        }
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        assert_eq!("".parse::<Time>(), Err(ParseUnitError::Empty));
+        assert_eq!("10".parse::<Time>(), Err(ParseUnitError::MissingUnit));
+        assert_eq!("10 jiffies".parse::<Time>(), Err(ParseUnitError::UnknownUnit("jiffies".to_string())));
+        assert_eq!("1 MS".parse::<Time>(), Err(ParseUnitError::UnknownUnit("MS".to_string())));
+        assert_eq!("1 M".parse::<Time>(), Err(ParseUnitError::UnknownUnit("M".to_string())));
+        assert_eq!("1 H".parse::<Time>(), Err(ParseUnitError::UnknownUnit("H".to_string())));
+    }
+
+    #[test]
+    fn test_parse_compound() {
+        assert_eq!(Time::parse_compound("1h 30m 15s"), Ok(Time::Seconds(5415.0)));
+        assert_eq!(Time::parse_compound("2d4h"), Ok(Time::Hours(52.0)));
+        assert_eq!(Time::parse_compound("1 hour 30 minutes"), Ok(Time::Minutes(90.0)));
+        assert_eq!(Time::parse_compound("-1h 30m"), Ok(Time::Minutes(-90.0)));
+        assert_eq!(Time::parse_compound("1.5h"), Ok(Time::Minutes(90.0)));
+        assert_eq!(Time::parse_compound("1s 250ms"), Ok(Time::Milliseconds(1250.0)));
+        assert_eq!(Time::parse_compound("2µs 500ns"), Ok(Time::Nanoseconds(2500.0)));
+        assert_eq!(Time::parse_compound("1wk 2d"), Ok(Time::Days(9.0)));
+        assert_eq!(Time::parse_compound("1h 5x"), Err(ParseUnitError::UnknownUnit("x".to_string())));
+        assert_eq!(Time::parse_compound("1h 30"), Err(ParseUnitError::MissingUnit));
+        assert_eq!(Time::parse_compound("-"), Err(ParseUnitError::Empty));
+    }
+
+    #[test]
+    fn test_to_compound_string() {
+        assert_eq!(Time::Seconds(5415.0).to_compound_string(), "1h 30m 15s");
+        assert_eq!(Time::Hours(52.0).to_compound_string(), "2d 4h");
+        assert_eq!(Time::Minutes(-90.0).to_compound_string(), "-1h 30m");
+        assert_eq!(Time::Seconds(0.0).to_compound_string(), "0s");
+        assert_eq!(Time::Seconds(61.5).to_compound_string(), "1m 1.5s");
+        assert_eq!(Time::Days(400.0).to_compound_string(), "400d");
+        assert_eq!(Time::Hours(1.1).to_compound_string(), "1h 6m");
+        assert_eq!(Time::Hours(-2.7).to_compound_string(), "-2h 42m");
+        assert_eq!(Time::Seconds(1.000_000_000_2).to_compound_string(), "1s");
+        assert_eq!(Time::Seconds(90.123_456_789).to_compound_string(), "1m 30.123456789s");
+        assert_eq!(Time::Seconds(-1e-12).to_compound_string(), "0s");
+    }
+
+    #[test]
+    fn test_compound_round_trip() {
+        let times = [
+            Time::Seconds(5415.0), Time::Seconds(0.25), Time::Minutes(-1.5), Time::Hours(49.75),
+            Time::Days(3.0), Time::Years(1.0), Time::Seconds(0.0),
+        ];
+        for time in times {
+            let compound = time.to_compound_string();
+            assert_eq!(Time::parse_compound(&compound), Ok(time), "{}", compound);
+        }
+    }
+
+    #[test]
+    fn test_from_duration() {
+        assert_eq!(Time::from(Duration::from_millis(1500)), Time::Seconds(1.5));
+        assert_eq!(Time::from(Duration::from_secs(90)), Time::Minutes(1.5));
+        assert_eq!(Time::from(Duration::ZERO), Time::Seconds(0.0));
+    }
+
+    #[test]
+    fn test_try_into_duration() {
+        assert_eq!(Duration::try_from(Time::Minutes(1.5)), Ok(Duration::from_secs(90)));
+        assert_eq!(Duration::try_from(Time::Seconds(0.25)), Ok(Duration::from_millis(250)));
+        assert_eq!(Duration::try_from(Time::Hours(-1.0)), Err(DurationError::Negative));
+        assert_eq!(Duration::try_from(Time::Seconds(f64::NAN)), Err(DurationError::NaN));
+        assert_eq!(Duration::try_from(Time::Seconds(f64::INFINITY)), Err(DurationError::Overflow));
+        assert_eq!(Duration::try_from(Time::Years(1.0e12)), Err(DurationError::Overflow));
+
+        let duration = Duration::from_nanos(1_234_567_890);
+        assert_eq!(Duration::try_from(Time::from(duration)), Ok(duration));
+    }
+
+    #[test]
+    fn test_as_duration_saturating() {
+        assert_eq!(Time::Seconds(2.0).as_duration_saturating(), Duration::from_secs(2));
+        assert_eq!(Time::Seconds(-2.0).as_duration_saturating(), Duration::ZERO);
+        assert_eq!(Time::Seconds(f64::NAN).as_duration_saturating(), Duration::ZERO);
+        assert_eq!(Time::Years(1.0e12).as_duration_saturating(), Duration::MAX);
+    }
+
+    #[test]
+    fn test_duration_ops() {
+        match Time::Minutes(1.0) + Duration::from_secs(30) {
+            Time::Minutes(x) => assert_eq!(x, 1.5),
+            _ => panic!("expected Time::Minutes variant"),
+        }
+        match Time::Hours(1.0) - Duration::from_secs(1800) {
+            Time::Hours(x) => assert_eq!(x, 0.5),
+            _ => panic!("expected Time::Hours variant"),
+        }
+        assert_eq!(Time::Seconds(1.0) - Duration::from_secs(2), Time::Seconds(-1.0));
+    }
+
+    #[test]
+    fn test_new_units() {
+        assert_eq!(Time::Milliseconds(1500.0), Time::Seconds(1.5));
+        assert_eq!(Time::Microseconds(1.0), Time::Nanoseconds(1000.0));
+        assert_eq!(Time::Weeks(2.0), Time::Fortnights(1.0));
+        assert_eq!(Time::Fortnights(1.0), Time::Days(14.0));
+        assert_eq!(Time::JulianYears(4.0), Time::Days(1461.0));
+        assert_eq!(Time::GregorianYears(400.0), Time::Days(146097.0));
+        assert!(Time::SiderealDays(1.0) < Time::Days(1.0));
+        assert!(Time::SiderealDays(1.0) > Time::Hours(23.93));
+
+        match Time::JulianYears(1.0).to(TimeUnit::Days) {
+            Time::Days(x) => assert_eq!(x, 365.25),
+            _ => panic!("expected Time::Days variant"),
+        }
+        match Time::Seconds(1.0).to(TimeUnit::Microseconds) {
+            Time::Microseconds(x) => assert_eq!(x, 1.0e6),
+            _ => panic!("expected Time::Microseconds variant"),
+        }
+    }
+
+    #[test]
+    fn test_new_units_ops() {
+        match Time::Milliseconds(500.0) + Time::Seconds(1.0) {
+            Time::Milliseconds(x) => assert!((x - 1500.0).abs() < 1e-9),
+            _ => panic!("expected Time::Milliseconds variant"),
+        }
+        match Time::Weeks(1.0) + Time::Days(7.0) {
+            Time::Weeks(x) => assert!((x - 2.0).abs() < 1e-12),
+            _ => panic!("expected Time::Weeks variant"),
+        }
+        match Time::GregorianYears(1.0) - Time::Years(1.0) {
+            Time::GregorianYears(x) => assert!((x - 0.2425 / 365.2425).abs() < 1e-12),
+            _ => panic!("expected Time::GregorianYears variant"),
+        }
+        match Time::Seconds(1.0) - Time::Nanoseconds(1.0) {
+            Time::Seconds(x) => assert!((x - 0.999999999).abs() < 1e-12),
+            _ => panic!("expected Time::Seconds variant"),
+        }
+        assert_eq!(format!("{}", Time::Microseconds(3.0)), "3 µs");
+        assert_eq!("3 µs".parse::<Time>(), Ok(Time::Microseconds(3.0)));
+        assert_eq!("1 Julian yr".parse::<Time>(), Ok(Time::JulianYears(1.0)));
+    }
+
+    fn one_of_each() -> Vec<Time> {
+        vec![
+            Time::Nanoseconds(1.0), Time::Microseconds(1.0), Time::Milliseconds(1.0),
+            Time::Seconds(1.0), Time::Minutes(1.0), Time::Hours(1.0), Time::Days(1.0),
+            Time::SiderealDays(1.0), Time::Weeks(1.0), Time::Fortnights(1.0), Time::Years(1.0),
+            Time::JulianYears(1.0), Time::GregorianYears(1.0),
+        ]
+    }
+
+    #[test]
+    fn test_add_sub_every_pair() {
+        for a in one_of_each() {
+            for b in one_of_each() {
+                let expected_sum = a.to_base().value() + b.to_base().value();
+                let expected_difference = a.to_base().value() - b.to_base().value();
+                let sum = a + b;
+                let difference = a - b;
+                assert_eq!(std::mem::discriminant(&sum), std::mem::discriminant(&a));
+                assert_eq!(std::mem::discriminant(&difference), std::mem::discriminant(&a));
+                assert!(((sum.to_base().value() - expected_sum) / expected_sum).abs() < 1e-12, "{:?} + {:?}", a, b);
+                assert!((difference.to_base().value() - expected_difference).abs() <= 1e-12 * expected_sum, "{:?} - {:?}", a, b);
+            }
+        }
+    }
+
+    /// The length of one of each unit in seconds, written out independently of the
+    /// `Time::SECONDS_PER_*` constants.
+    fn seconds_in(time: &Time) -> f64 {
+        match time {
+            Time::Nanoseconds(_) => 1.0e-9,
+            Time::Microseconds(_) => 1.0e-6,
+            Time::Milliseconds(_) => 1.0e-3,
+            Time::Seconds(_) => 1.0,
+            Time::Minutes(_) => 60.0,
+            Time::Hours(_) => 60.0 * 60.0,
+            Time::Days(_) => 24.0 * 60.0 * 60.0,
+            Time::SiderealDays(_) => 86164.098903691,
+            Time::Weeks(_) => 7.0 * 24.0 * 60.0 * 60.0,
+            Time::Fortnights(_) => 14.0 * 24.0 * 60.0 * 60.0,
+            Time::Years(_) => 365.0 * 24.0 * 60.0 * 60.0,
+            Time::JulianYears(_) => 365.25 * 24.0 * 60.0 * 60.0,
+            Time::GregorianYears(_) => 365.2425 * 24.0 * 60.0 * 60.0,
+        }
+    }
+
+    /// Adds or subtracts the way the former pairwise match arms did, scaling the right operand
+    /// straight into the left operand's unit, e.g. `Minutes(x + y * 60.0)` for hours.
+    fn pairwise(a: Time, b: Time, sign: f64) -> f64 {
+        a.value() + sign * b.value() * seconds_in(&b) / seconds_in(&a)
+    }
+
+    #[test]
+    fn test_add_sub_match_pairwise() {
+        for (x, y) in [(1.0, 1.0), (2.5, -0.75), (1.0e6, 3.0e-4), (-42.0, 1234.5)] {
+            for a in one_of_each() {
+                for b in one_of_each() {
+                    let (a, b) = (a * x, b * y);
+                    for (result, sign) in [(a + b, 1.0), (a - b, -1.0)] {
+                        assert_eq!(std::mem::discriminant(&result), std::mem::discriminant(&a));
+                        let expected = pairwise(a, b, sign);
+                        let scale = a.value().abs().max((b.value() * seconds_in(&b) / seconds_in(&a)).abs());
+                        assert!(
+                            (result.value() - expected).abs() <= 1e-12 * scale,
+                            "{:?} with {:?} (sign {}): got {:?}, expected {}", a, b, sign, result, expected
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
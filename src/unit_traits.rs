@@ -6,6 +6,9 @@ pub trait UnitConversion {
 
     /// Converts between units of the same dimension.
     fn to(&self, unit: Self::Units) -> Self;
+
+    /// Returns the unit the value is expressed in.
+    fn unit(&self) -> Self::Units;
 }
 
 pub trait Unit {
@@ -1,7 +1,127 @@
 // Allowing dead code since this is a utilities module
 #![allow(dead_code)]
 
+use std::cmp::Ordering;
+use std::fmt::{Alignment, Formatter, Write};
+
 pub fn round(value: f64, decimals: u32) -> f64 {
     let factor = 10_f64.powi(decimals as i32);
     (value * factor).round() / factor
-}
\ No newline at end of file
+}
+
+/// The largest difference `approx_eq` allows between two values, relative to the larger of them.
+pub const RELATIVE_TOLERANCE: f64 = 1e-12;
+
+/// Compares two values relative to their magnitude rather than with an absolute epsilon, so
+/// values at atomic scales are told apart as reliably as everyday ones. Zero only equals zero.
+pub fn approx_eq(a: f64, b: f64) -> bool {
+    a == b || (a - b).abs() <= RELATIVE_TOLERANCE * a.abs().max(b.abs())
+}
+
+/// Orders two values consistently with `approx_eq`, treating values it considers equal as equal.
+pub fn approx_cmp(a: f64, b: f64) -> Option<Ordering> {
+    if approx_eq(a, b) {
+        Some(Ordering::Equal)
+    } else {
+        a.partial_cmp(&b)
+    }
+}
+
+/// Asserts that two values agree to within a few units in the last place, relative to the
+/// larger of them, or exactly when either is zero.
+#[cfg(test)]
+pub(crate) fn assert_close(actual: f64, expected: f64) {
+    let error = (actual - expected).abs();
+    let scale = actual.abs().max(expected.abs());
+    assert!(error <= 1e-15 * scale, "{} != {} (difference {})", actual, expected, error);
+}
+
+/// Writes a quantity as its value followed by its unit, e.g. `1.5 km`, honoring the formatter's
+/// flags. Precision, the `+` sign and zero padding apply to the value, while width, fill and
+/// alignment apply to the quantity as a whole, which is right-aligned by default like a number.
+/// The alternate flag, `{:#}`, writes the unit's long name instead of its symbol.
+pub fn fmt_quantity(f: &mut Formatter, value: f64, symbol: &str, name: &str) -> std::fmt::Result {
+    let number = match (f.precision(), f.sign_plus()) {
+        (Some(precision), true) => format!("{:+.*}", precision, value),
+        (Some(precision), false) => format!("{:.*}", precision, value),
+        (None, true) => format!("{:+}", value),
+        (None, false) => format!("{}", value),
+    };
+    let unit = if f.alternate() { name } else { symbol };
+    let quantity = format!("{} {}", number, unit);
+
+    let padding = match f.width() {
+        Some(width) if width > quantity.chars().count() => width - quantity.chars().count(),
+        _ => return f.write_str(&quantity),
+    };
+    if f.sign_aware_zero_pad() {
+        let digits = number.trim_start_matches(['+', '-']);
+        let sign = &number[..number.len() - digits.len()];
+        return write!(f, "{}{}{} {}", sign, "0".repeat(padding), digits, unit);
+    }
+
+    let (before, after) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(&quantity)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+
+/*--------------------( Unit Tests )--------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_approx_eq() {
+        assert!(approx_eq(1.0, 1.0 + 1e-15));
+        assert!(approx_eq(1.66e-27, 1.66e-27 * (1.0 + 1e-15)));
+        assert!(!approx_eq(1.66e-27, 1.66e-18));
+        assert!(!approx_eq(1e-300, 0.0));
+        assert!(approx_eq(0.0, -0.0));
+        assert!(!approx_eq(f64::NAN, f64::NAN));
+        assert_eq!(approx_cmp(1.66e-27, 1.66e-18), Some(Ordering::Less));
+        assert_eq!(approx_cmp(373.15, 373.15 + 1e-13), Some(Ordering::Equal));
+    }
+
+    struct Quantity(f64);
+
+    impl std::fmt::Display for Quantity {
+        fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+            fmt_quantity(f, self.0, "km", "kilometers")
+        }
+    }
+
+    #[test]
+    fn test_fmt_quantity() {
+        let x = Quantity(1.2345);
+        assert_eq!(format!("{}", x), "1.2345 km");
+        assert_eq!(format!("{:.2}", x), "1.23 km");
+        assert_eq!(format!("{:+.1}", x), "+1.2 km");
+        assert_eq!(format!("{:#}", x), "1.2345 kilometers");
+        assert_eq!(format!("{:#.1}", x), "1.2 kilometers");
+        assert_eq!(format!("{:+}", Quantity(-3.0)), "-3 km");
+    }
+
+    #[test]
+    fn test_fmt_quantity_padding() {
+        let x = Quantity(1.5);
+        assert_eq!(format!("{:10}", x), "    1.5 km");
+        assert_eq!(format!("{:<10}", x), "1.5 km    ");
+        assert_eq!(format!("{:^10}", x), "  1.5 km  ");
+        assert_eq!(format!("{:*>10.2}", x), "***1.50 km");
+        assert_eq!(format!("{:010.2}", Quantity(-1.5)), "-001.50 km");
+        assert_eq!(format!("{:4}", x), "1.5 km");
+    }
+}
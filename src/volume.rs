@@ -0,0 +1,485 @@
+//! This module contains the `Volume` enum and its associated unit conversions.
+//! The `Volume` enum is used to represent volumes in cubic meters, liters, gallons, etc.
+//! The `VolumeUnit` enum is used to specify the unit of the volume.
+//! The `Volume` enum implements the `Unit` and `UnitConversion` traits, which offer the ability to 
+//! convert between different units of volume via the `to_base` and `to` methods.
+
+use std::ops::{Mul, Div};
+use std::fmt::Display;
+use crate::unit_traits::UnitConversion;
+use crate::unit_traits::Unit;
+use crate::length::{Length, LengthUnit};
+use crate::area::{Area, AreaUnit};
+use crate::quantity::Quantity;
+use crate::dimension;
+use crate::utils;
+
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[allow(non_camel_case_types)]
+pub enum VolumeUnit {
+    CubicMillimeters,
+    mm3,
+    CubicCentimeters,
+    cm3,
+    CubicMeters,
+    m3,
+    Milliliters,
+    mL,
+    Liters,
+    L,
+    CubicInches,
+    in3,
+    CubicFeet,
+    ft3,
+    UsFluidOunces,
+    fl_oz,
+    UsPints,
+    pt,
+    UsQuarts,
+    qt,
+    UsGallons,
+    gal,
+    ImperialGallons,
+    imp_gal,
+}
+
+
+/// The `Volume` enum represents volumes in cubic meters, liters, gallons, etc.
+#[derive(Debug, Clone, Copy)]
+pub enum Volume {
+    CubicMillimeters(f64),
+    CubicCentimeters(f64),
+    CubicMeters(f64),
+    Milliliters(f64),
+    Liters(f64),
+    CubicInches(f64),
+    CubicFeet(f64),
+    UsFluidOunces(f64),
+    UsPints(f64),
+    UsQuarts(f64),
+    UsGallons(f64),
+    ImperialGallons(f64),
+}
+
+impl Volume {
+    pub const CUBIC_METERS_PER_CUBIC_MILLIMETER: f64 = 1.0e-9;
+    pub const CUBIC_METERS_PER_CUBIC_CENTIMETER: f64 = 1.0e-6;
+    pub const CUBIC_METERS_PER_MILLILITER: f64 = 1.0e-6;
+    pub const CUBIC_METERS_PER_LITER: f64 = 1.0e-3;
+    pub const CUBIC_METERS_PER_CUBIC_INCH: f64 = 0.0254 * 0.0254 * 0.0254;
+    pub const CUBIC_METERS_PER_CUBIC_FOOT: f64 = 0.3048 * 0.3048 * 0.3048;
+    pub const CUBIC_METERS_PER_US_FLUID_OUNCE: f64 = 2.957_352_956_25e-5;
+    pub const CUBIC_METERS_PER_US_PINT: f64 = 4.731_764_73e-4;
+    pub const CUBIC_METERS_PER_US_QUART: f64 = 9.463_529_46e-4;
+    pub const CUBIC_METERS_PER_US_GALLON: f64 = 3.785_411_784e-3;
+    pub const CUBIC_METERS_PER_IMPERIAL_GALLON: f64 = 4.546_09e-3;
+
+    /* ALIASES */
+
+    pub fn mm3(x: f64) -> Volume {
+        Volume::CubicMillimeters(x)
+    }
+
+    pub fn cm3(x: f64) -> Volume {
+        Volume::CubicCentimeters(x)
+    }
+
+    pub fn m3(x: f64) -> Volume {
+        Volume::CubicMeters(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn mL(x: f64) -> Volume {
+        Volume::Milliliters(x)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn L(x: f64) -> Volume {
+        Volume::Liters(x)
+    }
+
+    pub fn in3(x: f64) -> Volume {
+        Volume::CubicInches(x)
+    }
+
+    pub fn ft3(x: f64) -> Volume {
+        Volume::CubicFeet(x)
+    }
+
+    pub fn fl_oz(x: f64) -> Volume {
+        Volume::UsFluidOunces(x)
+    }
+
+    pub fn pt(x: f64) -> Volume {
+        Volume::UsPints(x)
+    }
+
+    pub fn qt(x: f64) -> Volume {
+        Volume::UsQuarts(x)
+    }
+
+    pub fn gal(x: f64) -> Volume {
+        Volume::UsGallons(x)
+    }
+
+    pub fn imp_gal(x: f64) -> Volume {
+        Volume::ImperialGallons(x)
+    }
+}
+
+impl Unit for Volume {
+    /// Returns the inner f64 value of the Volume enum.
+    fn value(&self) -> f64 {
+        match self {
+            Volume::CubicMillimeters(x) => *x,
+            Volume::CubicCentimeters(x) => *x,
+            Volume::CubicMeters(x) => *x,
+            Volume::Milliliters(x) => *x,
+            Volume::Liters(x) => *x,
+            Volume::CubicInches(x) => *x,
+            Volume::CubicFeet(x) => *x,
+            Volume::UsFluidOunces(x) => *x,
+            Volume::UsPints(x) => *x,
+            Volume::UsQuarts(x) => *x,
+            Volume::UsGallons(x) => *x,
+            Volume::ImperialGallons(x) => *x,
+        }
+    }
+}
+
+impl UnitConversion for Volume {
+    type Units = VolumeUnit;
+
+    /// Returns the unit the Volume is expressed in.
+    fn unit(&self) -> VolumeUnit {
+        match self {
+            Volume::CubicMillimeters(_) => VolumeUnit::CubicMillimeters,
+            Volume::CubicCentimeters(_) => VolumeUnit::CubicCentimeters,
+            Volume::CubicMeters(_) => VolumeUnit::CubicMeters,
+            Volume::Milliliters(_) => VolumeUnit::Milliliters,
+            Volume::Liters(_) => VolumeUnit::Liters,
+            Volume::CubicInches(_) => VolumeUnit::CubicInches,
+            Volume::CubicFeet(_) => VolumeUnit::CubicFeet,
+            Volume::UsFluidOunces(_) => VolumeUnit::UsFluidOunces,
+            Volume::UsPints(_) => VolumeUnit::UsPints,
+            Volume::UsQuarts(_) => VolumeUnit::UsQuarts,
+            Volume::UsGallons(_) => VolumeUnit::UsGallons,
+            Volume::ImperialGallons(_) => VolumeUnit::ImperialGallons,
+        }
+    }
+
+    /// Converts a given instance of a Volume enum into the base variant, `Volume::CubicMeters`.
+    fn to_base(&self) -> Volume {
+        match self {
+            Volume::CubicMillimeters(x) => Volume::CubicMeters(x * Volume::CUBIC_METERS_PER_CUBIC_MILLIMETER),
+            Volume::CubicCentimeters(x) => Volume::CubicMeters(x * Volume::CUBIC_METERS_PER_CUBIC_CENTIMETER),
+            Volume::CubicMeters(x) => Volume::CubicMeters(*x),
+            Volume::Milliliters(x) => Volume::CubicMeters(x * Volume::CUBIC_METERS_PER_MILLILITER),
+            Volume::Liters(x) => Volume::CubicMeters(x * Volume::CUBIC_METERS_PER_LITER),
+            Volume::CubicInches(x) => Volume::CubicMeters(x * Volume::CUBIC_METERS_PER_CUBIC_INCH),
+            Volume::CubicFeet(x) => Volume::CubicMeters(x * Volume::CUBIC_METERS_PER_CUBIC_FOOT),
+            Volume::UsFluidOunces(x) => Volume::CubicMeters(x * Volume::CUBIC_METERS_PER_US_FLUID_OUNCE),
+            Volume::UsPints(x) => Volume::CubicMeters(x * Volume::CUBIC_METERS_PER_US_PINT),
+            Volume::UsQuarts(x) => Volume::CubicMeters(x * Volume::CUBIC_METERS_PER_US_QUART),
+            Volume::UsGallons(x) => Volume::CubicMeters(x * Volume::CUBIC_METERS_PER_US_GALLON),
+            Volume::ImperialGallons(x) => Volume::CubicMeters(x * Volume::CUBIC_METERS_PER_IMPERIAL_GALLON),
+        }
+    }
+
+    /// Converts a given instance of a Volume enum into the specified variant as indicated by
+    /// the `VolumeUnit` provided.
+    fn to(&self, variant: VolumeUnit) -> Volume {
+        let base = self.to_base().value();
+        match variant {
+            VolumeUnit::CubicMillimeters | VolumeUnit::mm3 => Volume::CubicMillimeters(base / Volume::CUBIC_METERS_PER_CUBIC_MILLIMETER),
+            VolumeUnit::CubicCentimeters | VolumeUnit::cm3 => Volume::CubicCentimeters(base / Volume::CUBIC_METERS_PER_CUBIC_CENTIMETER),
+            VolumeUnit::CubicMeters | VolumeUnit::m3 => Volume::CubicMeters(base),
+            VolumeUnit::Milliliters | VolumeUnit::mL => Volume::Milliliters(base / Volume::CUBIC_METERS_PER_MILLILITER),
+            VolumeUnit::Liters | VolumeUnit::L => Volume::Liters(base / Volume::CUBIC_METERS_PER_LITER),
+            VolumeUnit::CubicInches | VolumeUnit::in3 => Volume::CubicInches(base / Volume::CUBIC_METERS_PER_CUBIC_INCH),
+            VolumeUnit::CubicFeet | VolumeUnit::ft3 => Volume::CubicFeet(base / Volume::CUBIC_METERS_PER_CUBIC_FOOT),
+            VolumeUnit::UsFluidOunces | VolumeUnit::fl_oz => Volume::UsFluidOunces(base / Volume::CUBIC_METERS_PER_US_FLUID_OUNCE),
+            VolumeUnit::UsPints | VolumeUnit::pt => Volume::UsPints(base / Volume::CUBIC_METERS_PER_US_PINT),
+            VolumeUnit::UsQuarts | VolumeUnit::qt => Volume::UsQuarts(base / Volume::CUBIC_METERS_PER_US_QUART),
+            VolumeUnit::UsGallons | VolumeUnit::gal => Volume::UsGallons(base / Volume::CUBIC_METERS_PER_US_GALLON),
+            VolumeUnit::ImperialGallons | VolumeUnit::imp_gal => Volume::ImperialGallons(base / Volume::CUBIC_METERS_PER_IMPERIAL_GALLON),
+        }
+    }
+}
+
+
+impl Mul<f64> for Volume {
+    type Output = Volume;
+
+    fn mul(self, other: f64) -> Volume {
+        match self {
+            Volume::CubicMillimeters(x) => Volume::CubicMillimeters(x * other),
+            Volume::CubicCentimeters(x) => Volume::CubicCentimeters(x * other),
+            Volume::CubicMeters(x) => Volume::CubicMeters(x * other),
+            Volume::Milliliters(x) => Volume::Milliliters(x * other),
+            Volume::Liters(x) => Volume::Liters(x * other),
+            Volume::CubicInches(x) => Volume::CubicInches(x * other),
+            Volume::CubicFeet(x) => Volume::CubicFeet(x * other),
+            Volume::UsFluidOunces(x) => Volume::UsFluidOunces(x * other),
+            Volume::UsPints(x) => Volume::UsPints(x * other),
+            Volume::UsQuarts(x) => Volume::UsQuarts(x * other),
+            Volume::UsGallons(x) => Volume::UsGallons(x * other),
+            Volume::ImperialGallons(x) => Volume::ImperialGallons(x * other),
+        }
+    }
+}
+
+
+impl Mul<Volume> for f64 {
+    type Output = Volume;
+
+    fn mul(self, other: Volume) -> Volume {
+        match other {
+            Volume::CubicMillimeters(x) => Volume::CubicMillimeters(x * self),
+            Volume::CubicCentimeters(x) => Volume::CubicCentimeters(x * self),
+            Volume::CubicMeters(x) => Volume::CubicMeters(x * self),
+            Volume::Milliliters(x) => Volume::Milliliters(x * self),
+            Volume::Liters(x) => Volume::Liters(x * self),
+            Volume::CubicInches(x) => Volume::CubicInches(x * self),
+            Volume::CubicFeet(x) => Volume::CubicFeet(x * self),
+            Volume::UsFluidOunces(x) => Volume::UsFluidOunces(x * self),
+            Volume::UsPints(x) => Volume::UsPints(x * self),
+            Volume::UsQuarts(x) => Volume::UsQuarts(x * self),
+            Volume::UsGallons(x) => Volume::UsGallons(x * self),
+            Volume::ImperialGallons(x) => Volume::ImperialGallons(x * self),
+        }
+    }
+}
+
+
+impl Div<f64> for Volume {
+    type Output = Volume;
+
+    fn div(self, other: f64) -> Volume {
+        match self {
+            Volume::CubicMillimeters(x) => Volume::CubicMillimeters(x / other),
+            Volume::CubicCentimeters(x) => Volume::CubicCentimeters(x / other),
+            Volume::CubicMeters(x) => Volume::CubicMeters(x / other),
+            Volume::Milliliters(x) => Volume::Milliliters(x / other),
+            Volume::Liters(x) => Volume::Liters(x / other),
+            Volume::CubicInches(x) => Volume::CubicInches(x / other),
+            Volume::CubicFeet(x) => Volume::CubicFeet(x / other),
+            Volume::UsFluidOunces(x) => Volume::UsFluidOunces(x / other),
+            Volume::UsPints(x) => Volume::UsPints(x / other),
+            Volume::UsQuarts(x) => Volume::UsQuarts(x / other),
+            Volume::UsGallons(x) => Volume::UsGallons(x / other),
+            Volume::ImperialGallons(x) => Volume::ImperialGallons(x / other),
+        }
+    }
+}
+
+
+impl Display for Volume {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (x, symbol, name) = match self {
+            Volume::CubicMillimeters(x) => (x, "mm³", "cubic millimeters"),
+            Volume::CubicCentimeters(x) => (x, "cm³", "cubic centimeters"),
+            Volume::CubicMeters(x) => (x, "m³", "cubic meters"),
+            Volume::Milliliters(x) => (x, "mL", "milliliters"),
+            Volume::Liters(x) => (x, "L", "liters"),
+            Volume::CubicInches(x) => (x, "in³", "cubic inches"),
+            Volume::CubicFeet(x) => (x, "ft³", "cubic feet"),
+            Volume::UsFluidOunces(x) => (x, "fl oz", "US fluid ounces"),
+            Volume::UsPints(x) => (x, "pt", "US pints"),
+            Volume::UsQuarts(x) => (x, "qt", "US quarts"),
+            Volume::UsGallons(x) => (x, "gal", "US gallons"),
+            Volume::ImperialGallons(x) => (x, "imp gal", "imperial gallons"),
+        };
+        utils::fmt_quantity(f, *x, symbol, name)
+    }
+}
+
+
+impl Mul<Length> for Area {
+    type Output = Volume;
+
+    /// Multiplies an area by a length. Square millimeters, centimeters, meters, inches and feet
+    /// produce their cubic counterparts; other imperial areas produce cubic feet and other metric
+    /// areas produce cubic meters.
+    fn mul(self, other: Length) -> Volume {
+        let unit = match self {
+            Area::SqMillimeters(_) => VolumeUnit::CubicMillimeters,
+            Area::SqCentimeters(_) => VolumeUnit::CubicCentimeters,
+            Area::SqMeters(_) | Area::SqKilometers(_) | Area::Hectares(_) => VolumeUnit::CubicMeters,
+            Area::SqInches(_) => VolumeUnit::CubicInches,
+            Area::SqFeet(_) | Area::SqYards(_) | Area::SqMiles(_) | Area::Acres(_) => VolumeUnit::CubicFeet,
+        };
+        let volume: Quantity<dimension::Volume> = Quantity::from(self) * Quantity::from(other);
+        Volume::from(volume).to(unit)
+    }
+}
+
+
+impl Div<Length> for Volume {
+    type Output = Area;
+
+    /// Divides a volume by a length. Cubic units produce their square counterparts, milliliters
+    /// produce square centimeters, liters produce square meters and the US customary and imperial
+    /// liquid units produce square inches.
+    fn div(self, other: Length) -> Area {
+        let unit = match self {
+            Volume::CubicMillimeters(_) => AreaUnit::SqMillimeters,
+            Volume::CubicCentimeters(_) | Volume::Milliliters(_) => AreaUnit::SqCentimeters,
+            Volume::CubicMeters(_) | Volume::Liters(_) => AreaUnit::SqMeters,
+            Volume::CubicFeet(_) => AreaUnit::SqFeet,
+            Volume::CubicInches(_) | Volume::UsFluidOunces(_) | Volume::UsPints(_) | Volume::UsQuarts(_)
+            | Volume::UsGallons(_) | Volume::ImperialGallons(_) => AreaUnit::SqInches,
+        };
+        let area: Quantity<dimension::Area> = Quantity::from(self) / Quantity::from(other);
+        Area::from(area).to(unit)
+    }
+}
+
+
+impl Div<Area> for Volume {
+    type Output = Length;
+
+    /// Divides a volume by an area, producing a length in the unit matching the volume's unit
+    /// using the same families as `Volume / Length`.
+    fn div(self, other: Area) -> Length {
+        let unit = match self {
+            Volume::CubicMillimeters(_) => LengthUnit::Millimeters,
+            Volume::CubicCentimeters(_) | Volume::Milliliters(_) => LengthUnit::Centimeters,
+            Volume::CubicMeters(_) | Volume::Liters(_) => LengthUnit::Meters,
+            Volume::CubicFeet(_) => LengthUnit::Feet,
+            Volume::CubicInches(_) | Volume::UsFluidOunces(_) | Volume::UsPints(_) | Volume::UsQuarts(_)
+            | Volume::UsGallons(_) | Volume::ImperialGallons(_) => LengthUnit::Inches,
+        };
+        let length: Quantity<dimension::Length> = Quantity::from(self) / Quantity::from(other);
+        Length::from(length).to(unit)
+    }
+}
+
+
+/*--------------------( Unit Tests )--------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::assert_close;
+
+    #[test]
+    fn test_to_base() {
+        let volume = Volume::Liters(1500.0);
+        match volume.to_base() {
+            Volume::CubicMeters(x) => assert_eq!(x, 1.5),
+            _ => panic!("expected Volume::CubicMeters variant"),
+        }
+    }
+
+    #[test]
+    fn test_from_base() {
+        let volume = Volume::CubicMeters(2.0);
+        match volume.to(VolumeUnit::L) {
+            Volume::Liters(x) => assert_eq!(x, 2000.0),
+            _ => panic!("expected Volume::Liters variant"),
+        }
+    }
+
+    #[test]
+    fn test_us_customary_conversions() {
+        // A US gallon is defined as 231 cubic inches
+        let gallon = Volume::UsGallons(1.0);
+        assert!((gallon.to(VolumeUnit::CubicInches).value() - 231.0).abs() < 1e-9);
+        assert!((gallon.to(VolumeUnit::UsQuarts).value() - 4.0).abs() < 1e-12);
+        assert!((gallon.to(VolumeUnit::UsPints).value() - 8.0).abs() < 1e-12);
+        assert!((gallon.to(VolumeUnit::UsFluidOunces).value() - 128.0).abs() < 1e-9);
+        let cubic_foot = Volume::CubicFeet(1.0);
+        assert!((cubic_foot.to(VolumeUnit::CubicInches).value() - 1728.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_imperial_conversions() {
+        let gallon = Volume::ImperialGallons(1.0);
+        assert!((gallon.to(VolumeUnit::Liters).value() - 4.54609).abs() < 1e-12);
+        assert!(gallon > Volume::UsGallons(1.0));
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(Volume::Milliliters(1.0), Volume::CubicCentimeters(1.0));
+        assert_eq!(Volume::Liters(1.0), Volume::CubicCentimeters(1000.0));
+        assert_eq!(Volume::CubicMeters(1.0), Volume::Liters(1000.0));
+        assert_eq!(Volume::Liters(2.6e6).to(VolumeUnit::ImperialGallons), Volume::Liters(2.6e6));
+        assert_eq!(Volume::CubicFeet(1.1e3), Volume::CubicInches(1.1e3 * 1728.0));
+    }
+
+    #[test]
+    fn test_add_sub() {
+        match Volume::Liters(1.0) + Volume::Milliliters(250.0) {
+            Volume::Liters(x) => assert_eq!(x, 1.25),
+            _ => panic!("expected Volume::Liters variant"),
+        }
+        match Volume::CubicMeters(1.0) - Volume::Liters(250.0) {
+            Volume::CubicMeters(x) => assert_eq!(x, 0.75),
+            _ => panic!("expected Volume::CubicMeters variant"),
+        }
+    }
+
+    #[test]
+    fn test_scalar_ops() {
+        let volume = Volume::UsGallons(5.0);
+        assert_eq!(volume * 2.0, Volume::UsGallons(10.0));
+        assert_eq!(2.0 * volume, Volume::UsGallons(10.0));
+        assert_eq!(volume / 5.0, Volume::UsGallons(1.0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Volume::CubicMeters(1.5)), "1.5 m³");
+        assert_eq!(format!("{}", Volume::Liters(2.0)), "2 L");
+        assert_eq!(format!("{}", Volume::UsFluidOunces(8.0)), "8 fl oz");
+        assert_eq!(format!("{}", Volume::ImperialGallons(1.0)), "1 imp gal");
+    }
+
+    #[test]
+    fn test_area_times_length() {
+        let room = Area::SqFeet(250.0) * Length::Feet(8.0);
+        match room {
+            Volume::CubicFeet(x) => assert_close(x, 2000.0),
+            _ => panic!("expected Volume::CubicFeet variant"),
+        }
+
+        let pond = Area::Hectares(1.0) * Length::Meters(0.1);
+        match pond {
+            Volume::CubicMeters(x) => assert!((x - 1000.0).abs() < 1e-9),
+            _ => panic!("expected Volume::CubicMeters variant"),
+        }
+    }
+
+    #[test]
+    fn test_volume_div_length() {
+        let footprint = Volume::CubicMeters(12.0) / Length::Meters(3.0);
+        match footprint {
+            Area::SqMeters(x) => assert_eq!(x, 4.0),
+            _ => panic!("expected Area::SqMeters variant"),
+        }
+
+        let base = Volume::UsGallons(1.0) / Length::Inches(7.0);
+        match base {
+            Area::SqInches(x) => assert!((x - 33.0).abs() < 1e-9),
+            _ => panic!("expected Area::SqInches variant"),
+        }
+    }
+
+    #[test]
+    fn test_volume_div_area() {
+        let depth = Volume::CubicFeet(2000.0) / Area::SqFeet(250.0);
+        match depth {
+            Length::Feet(x) => assert_eq!(x, 8.0),
+            _ => panic!("expected Length::Feet variant"),
+        }
+
+        let depth = Volume::Liters(500.0) / Area::SqMeters(2.0);
+        match depth {
+            Length::Meters(x) => assert_eq!(x, 0.25),
+            _ => panic!("expected Length::Meters variant"),
+        }
+    }
+}
@@ -45,16 +45,51 @@ fn itest_data_conversation() {
 
 #[test]
 fn itest_data_rates() {
-    let data = Data::MB(2048.0);
+    let data = Data::MiB(2048.0);
     let time = Time::Seconds(1.0);
     let rate = data / time;
 
-    // Make sure what we got is in MBps and the value is correct
+    // Make sure what we got is in MiBps and the value is correct
     match rate {
-        DataRate::MBps(x) => assert_eq!(x, 2048.0),
-        _ => panic!("expected DataRate::MBps variant"),
+        DataRate::MiBps(x) => assert_eq!(x, 2048.0),
+        _ => panic!("expected DataRate::MiBps variant"),
     }
 
     let gibps = rate.to(unit::GiBps).value();
     assert_eq!(gibps, 2.0);
+
+    // Decimal and binary prefixes are not interchangeable
+    let rate = Data::MB(2048.0) / time;
+    let gibps = rate.to(unit::GiBps).value();
+    assert_eq!(gibps, 2.048e9 / Data::BYTES_PER_GBI);
+}
+
+#[test]
+fn itest_data_rate_inverses() {
+    let rate = DataRate::Gbps(2.5);
+    let time = Time::Hours(1.0);
+
+    // A 2.5 Gbps link moves 1125 GB in an hour, in either operand order
+    let moved = rate * time;
+    match moved {
+        Data::Gigabytes(x) => assert_eq!(x, 1125.0),
+        _ => panic!("expected Data::Gigabytes variant"),
+    }
+    assert_eq!(time * rate, moved);
+
+    // How long does a 4 TiB backup take over a 2.5 Gbps link?
+    let backup = Data::Tebibytes(4.0) / DataRate::Gbps(2.5);
+    let expected = 4.0 * Data::BYTES_PER_TBI * 8.0 / 2.5e9;
+    assert_eq!(backup, Time::Seconds(expected));
+    assert!(backup > Time::Hours(3.9) && backup < Time::Hours(4.0));
+
+    // Round trip: the data moved over the transfer time is the original data
+    let data = Data::Megabytes(500.0);
+    let rate = DataRate::MBps(20.0);
+    let moved = rate * (data / rate);
+    match moved {
+        Data::Megabytes(x) => assert_eq!(x, 500.0),
+        _ => panic!("expected Data::Megabytes variant"),
+    }
 }
+
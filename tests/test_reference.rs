@@ -0,0 +1,205 @@
+//! Validates every conversion factor of `Length`, `Time`, `Data` and `DataRate` against the
+//! reference values checked in at `tests/data/reference_factors.csv`.
+//!
+//! The `*_variants` functions below match exhaustively, so adding a variant to one of the unit
+//! types fails to compile until it is listed here, and the harness then fails until the new unit
+//! has a row in the reference table.
+
+use std::collections::BTreeMap;
+use std::ops::Mul;
+use unit_types::{Data, DataRate, Length, Time};
+use unit_types::data::DataUnit;
+use unit_types::data_rate::DataRateUnit;
+use unit_types::length::LengthUnit;
+use unit_types::time::TimeUnit;
+use unit_types::prelude::*;
+
+const REFERENCE: &str = include_str!("data/reference_factors.csv");
+
+/// The largest relative error tolerated between a conversion and the reference value.
+const MAX_RELATIVE_ERROR: f64 = 1e-12;
+
+/// The magnitudes each unit is exercised with.
+const SAMPLES: [f64; 6] = [1.0, 0.5, 3.0, 1234.5678, 1.0e-3, 7.0e9];
+
+/// Loads the factors of a single dimension from the reference table, keyed by unit name.
+fn reference(dimension: &str) -> BTreeMap<String, f64> {
+    let mut factors = BTreeMap::new();
+    for line in REFERENCE.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(',').collect();
+        assert_eq!(fields.len(), 5, "malformed reference row: {}", line);
+        if fields[0] != dimension {
+            continue;
+        }
+        let factor = fields[3].parse::<f64>().expect("reference factor is not a number");
+        let previous = factors.insert(fields[1].to_string(), factor);
+        assert!(previous.is_none(), "duplicate reference row for {} {}", dimension, fields[1]);
+    }
+    assert!(!factors.is_empty(), "no reference rows for {}", dimension);
+    factors
+}
+
+fn assert_close(actual: f64, expected: f64, context: &str) {
+    let error = if expected == 0.0 { actual.abs() } else { ((actual - expected) / expected).abs() };
+    assert!(
+        error < MAX_RELATIVE_ERROR,
+        "{}: got {}, expected {} (relative error {:e})", context, actual, expected, error
+    );
+}
+
+/// Converts every variant to base and back, and to every other variant, at each sample
+/// magnitude, comparing against the reference factors.
+///
+/// `variants` yields one of each variant valued at 1.0, its name in the reference table and its
+/// unit. It is a function rather than a list because the unit enums are not all `Copy`.
+fn check<T, U>(dimension: &str, variants: fn() -> Vec<(T, &'static str, U)>)
+where
+    T: UnitConversion<Units = U> + Unit + Mul<f64, Output = T> + Copy,
+{
+    let factors = reference(dimension);
+    let names: Vec<&str> = variants().into_iter().map(|(_, name, _)| name).collect();
+    for name in factors.keys() {
+        assert!(names.contains(&name.as_str()), "{} reference row {} matches no variant", dimension, name);
+    }
+
+    for (index, (from, from_name, _)) in variants().into_iter().enumerate() {
+        let from_factor = *factors
+            .get(from_name)
+            .unwrap_or_else(|| panic!("{} {} has no reference row", dimension, from_name));
+
+        for sample in SAMPLES {
+            let x = from * sample;
+            let context = format!("{} {} {}", dimension, sample, from_name);
+            assert_close(x.to_base().value(), sample * from_factor, &format!("{} to base", context));
+
+            // Units are consumed by `to`, so each conversion takes a fresh one from `variants`.
+            let (_, _, from_unit) = variants().swap_remove(index);
+            assert_close(x.to_base().to(from_unit).value(), sample, &format!("{} from base", context));
+
+            for (_, to_name, to_unit) in variants() {
+                let to_factor = factors[to_name];
+                assert_close(
+                    x.to(to_unit).value(),
+                    sample * from_factor / to_factor,
+                    &format!("{} to {}", context, to_name),
+                );
+            }
+        }
+    }
+}
+
+fn length_variants() -> Vec<(Length, &'static str, LengthUnit)> {
+    let all = [
+        Length::Millimeters(1.0), Length::Centimeters(1.0), Length::Meters(1.0),
+        Length::Kilometers(1.0), Length::Feet(1.0), Length::Inches(1.0), Length::Yards(1.0),
+        Length::Miles(1.0), Length::NauticalMiles(1.0),
+    ];
+    all.into_iter().map(|x| match x {
+        Length::Millimeters(_) => (x, "Millimeters", LengthUnit::Millimeters),
+        Length::Centimeters(_) => (x, "Centimeters", LengthUnit::Centimeters),
+        Length::Meters(_) => (x, "Meters", LengthUnit::Meters),
+        Length::Kilometers(_) => (x, "Kilometers", LengthUnit::Kilometers),
+        Length::Feet(_) => (x, "Feet", LengthUnit::Feet),
+        Length::Inches(_) => (x, "Inches", LengthUnit::Inches),
+        Length::Yards(_) => (x, "Yards", LengthUnit::Yards),
+        Length::Miles(_) => (x, "Miles", LengthUnit::Miles),
+        Length::NauticalMiles(_) => (x, "NauticalMiles", LengthUnit::NauticalMiles),
+    }).collect()
+}
+
+fn time_variants() -> Vec<(Time, &'static str, TimeUnit)> {
+    let all = [
+        Time::Nanoseconds(1.0), Time::Microseconds(1.0), Time::Milliseconds(1.0),
+        Time::Seconds(1.0), Time::Minutes(1.0), Time::Hours(1.0), Time::Days(1.0),
+        Time::SiderealDays(1.0), Time::Weeks(1.0), Time::Fortnights(1.0), Time::Years(1.0),
+        Time::JulianYears(1.0), Time::GregorianYears(1.0),
+    ];
+    all.into_iter().map(|x| match x {
+        Time::Nanoseconds(_) => (x, "Nanoseconds", TimeUnit::Nanoseconds),
+        Time::Microseconds(_) => (x, "Microseconds", TimeUnit::Microseconds),
+        Time::Milliseconds(_) => (x, "Milliseconds", TimeUnit::Milliseconds),
+        Time::Seconds(_) => (x, "Seconds", TimeUnit::Seconds),
+        Time::Minutes(_) => (x, "Minutes", TimeUnit::Minutes),
+        Time::Hours(_) => (x, "Hours", TimeUnit::Hours),
+        Time::Days(_) => (x, "Days", TimeUnit::Days),
+        Time::SiderealDays(_) => (x, "SiderealDays", TimeUnit::SiderealDays),
+        Time::Weeks(_) => (x, "Weeks", TimeUnit::Weeks),
+        Time::Fortnights(_) => (x, "Fortnights", TimeUnit::Fortnights),
+        Time::Years(_) => (x, "Years", TimeUnit::Years),
+        Time::JulianYears(_) => (x, "JulianYears", TimeUnit::JulianYears),
+        Time::GregorianYears(_) => (x, "GregorianYears", TimeUnit::GregorianYears),
+    }).collect()
+}
+
+fn data_variants() -> Vec<(Data, &'static str, DataUnit)> {
+    let all = [
+        Data::Bytes(1.0), Data::Kilobytes(1.0), Data::Megabytes(1.0), Data::Gigabytes(1.0),
+        Data::Terabytes(1.0), Data::Kibibytes(1.0), Data::Mebibytes(1.0), Data::Gibibytes(1.0),
+        Data::Tebibytes(1.0),
+    ];
+    all.into_iter().map(|x| match x {
+        Data::Bytes(_) => (x, "Bytes", DataUnit::Bytes),
+        Data::Kilobytes(_) => (x, "Kilobytes", DataUnit::Kilobytes),
+        Data::Megabytes(_) => (x, "Megabytes", DataUnit::Megabytes),
+        Data::Gigabytes(_) => (x, "Gigabytes", DataUnit::Gigabytes),
+        Data::Terabytes(_) => (x, "Terabytes", DataUnit::Terabytes),
+        Data::Kibibytes(_) => (x, "Kibibytes", DataUnit::Kibibytes),
+        Data::Mebibytes(_) => (x, "Mebibytes", DataUnit::Mebibytes),
+        Data::Gibibytes(_) => (x, "Gibibytes", DataUnit::Gibibytes),
+        Data::Tebibytes(_) => (x, "Tebibytes", DataUnit::Tebibytes),
+    }).collect()
+}
+
+fn data_rate_variants() -> Vec<(DataRate, &'static str, DataRateUnit)> {
+    let all = [
+        DataRate::bps(1.0), DataRate::Kbps(1.0), DataRate::KBps(1.0), DataRate::Kibps(1.0),
+        DataRate::KiBps(1.0), DataRate::Mbps(1.0), DataRate::MBps(1.0), DataRate::Mibps(1.0),
+        DataRate::MiBps(1.0), DataRate::Gbps(1.0), DataRate::GBps(1.0), DataRate::Gibps(1.0),
+        DataRate::GiBps(1.0), DataRate::Tbps(1.0), DataRate::TBps(1.0), DataRate::Tibps(1.0),
+        DataRate::TiBps(1.0),
+    ];
+    all.into_iter().map(|x| match x {
+        DataRate::bps(_) => (x, "bps", DataRateUnit::bps),
+        DataRate::Kbps(_) => (x, "Kbps", DataRateUnit::Kbps),
+        DataRate::KBps(_) => (x, "KBps", DataRateUnit::KBps),
+        DataRate::Kibps(_) => (x, "Kibps", DataRateUnit::Kibps),
+        DataRate::KiBps(_) => (x, "KiBps", DataRateUnit::KiBps),
+        DataRate::Mbps(_) => (x, "Mbps", DataRateUnit::Mbps),
+        DataRate::MBps(_) => (x, "MBps", DataRateUnit::MBps),
+        DataRate::Mibps(_) => (x, "Mibps", DataRateUnit::Mibps),
+        DataRate::MiBps(_) => (x, "MiBps", DataRateUnit::MiBps),
+        DataRate::Gbps(_) => (x, "Gbps", DataRateUnit::Gbps),
+        DataRate::GBps(_) => (x, "GBps", DataRateUnit::GBps),
+        DataRate::Gibps(_) => (x, "Gibps", DataRateUnit::Gibps),
+        DataRate::GiBps(_) => (x, "GiBps", DataRateUnit::GiBps),
+        DataRate::Tbps(_) => (x, "Tbps", DataRateUnit::Tbps),
+        DataRate::TBps(_) => (x, "TBps", DataRateUnit::TBps),
+        DataRate::Tibps(_) => (x, "Tibps", DataRateUnit::Tibps),
+        DataRate::TiBps(_) => (x, "TiBps", DataRateUnit::TiBps),
+    }).collect()
+}
+
+
+#[test]
+fn itest_reference_length() {
+    check("length", length_variants);
+}
+
+#[test]
+fn itest_reference_time() {
+    check("time", time_variants);
+}
+
+#[test]
+fn itest_reference_data() {
+    check("data", data_variants);
+}
+
+#[test]
+fn itest_reference_data_rate() {
+    check("data_rate", data_rate_variants);
+}